@@ -19,9 +19,7 @@ fn main() {
                 .attr("summary", "Here is the beginning of some blog post.")
                 .rel(
                     "author",
-                    jsonapis::ResourceBuilder::new_with_id("users", "1")
-                        .link("self", "http://example.com/users/1.json")
-                        .attr("username", "alice"),
+                    jsonapis::ResourceIdentifierBuilder::new("users", "1"),
                 ),
             jsonapis::ResourceBuilder::new_with_id("posts", "2")
                 .link("self", "http://example.com/posts/2.json")
@@ -29,17 +27,24 @@ fn main() {
                 .attr("summary", "Here is the beginning of other blog post.")
                 .rel(
                     "author",
-                    jsonapis::ResourceBuilder::new_with_id("users", "2")
-                        .link("self", "http://example.com/users/2.json")
-                        .attr("username", "bob"),
+                    jsonapis::ResourceIdentifierBuilder::new("users", "2"),
                 ),
         ])
+        .include(
+            jsonapis::ResourceBuilder::new_with_id("users", "1")
+                .link("self", "http://example.com/users/1.json")
+                .attr("username", "alice"),
+        )
+        .include(
+            jsonapis::ResourceBuilder::new_with_id("users", "2")
+                .link("self", "http://example.com/users/2.json")
+                .attr("username", "bob"),
+        )
         .unwrap();
 
     let expected_value = json!({
         "jsonapi": json!({
             "version": json!("1.0"),
-            "meta": json!(null),
         }),
         "meta": json!({
             "current_page": json!(1),
@@ -49,26 +54,16 @@ fn main() {
         }),
         "links": json!({
             "self": json!("http://example.com/posts.json?page=1"),
-            "related": json!(null),
             "first": json!("http://example.com/posts.json?page=1"),
             "last": json!("http://example.com/posts.json?page=3"),
-            "prev": json!(null),
             "next": json!("http://example.com/posts.json?page=2"),
-            "about": json!(null),
         }),
         "data": json!([
             json!({
                 "type": json!("posts"),
                 "id": json!("1"),
-                "meta": json!(null),
                 "links": json!({
                     "self": json!("http://example.com/posts/1.json"),
-                    "related": json!(null),
-                    "first": json!(null),
-                    "last": json!(null),
-                    "prev": json!(null),
-                    "next": json!(null),
-                    "about": json!(null),
                 }),
                 "attributes": json!({
                     "title": json!("Some blog post"),
@@ -76,25 +71,9 @@ fn main() {
                 }),
                 "relationships": json!({
                     "author": json!({
-                        "meta": json!(null),
-                        "links": json!(null),
                         "data": json!({
                             "type": json!("users"),
                             "id": json!("1"),
-                            "meta": json!(null),
-                            "links": json!({
-                                "self": json!("http://example.com/users/1.json"),
-                                "related": json!(null),
-                                "first": json!(null),
-                                "last": json!(null),
-                                "prev": json!(null),
-                                "next": json!(null),
-                                "about": json!(null),
-                            }),
-                            "attributes": json!({
-                                "username": json!("alice"),
-                            }),
-                            "relationships": json!(null),
                         }),
                     }),
                 }),
@@ -102,15 +81,8 @@ fn main() {
             json!({
                 "type": json!("posts"),
                 "id": json!("2"),
-                "meta": json!(null),
                 "links": json!({
                     "self": json!("http://example.com/posts/2.json"),
-                    "related": json!(null),
-                    "first": json!(null),
-                    "last": json!(null),
-                    "prev": json!(null),
-                    "next": json!(null),
-                    "about": json!(null),
                 }),
                 "attributes": json!({
                     "title": json!("Other blog post"),
@@ -118,31 +90,36 @@ fn main() {
                 }),
                 "relationships": json!({
                     "author": json!({
-                        "meta": json!(null),
-                        "links": json!(null),
                         "data": json!({
                             "type": json!("users"),
                             "id": json!("2"),
-                            "meta": json!(null),
-                            "links": json!({
-                                "self": json!("http://example.com/users/2.json"),
-                                "related": json!(null),
-                                "first": json!(null),
-                                "last": json!(null),
-                                "prev": json!(null),
-                                "next": json!(null),
-                                "about": json!(null),
-                            }),
-                            "attributes": json!({
-                                "username": json!("bob"),
-                            }),
-                            "relationships": json!(null),
                         }),
                     }),
                 }),
             }),
         ]),
-        "errors": json!(null),
+        "included": json!([
+            json!({
+                "type": json!("users"),
+                "id": json!("1"),
+                "links": json!({
+                    "self": json!("http://example.com/users/1.json"),
+                }),
+                "attributes": json!({
+                    "username": json!("alice"),
+                }),
+            }),
+            json!({
+                "type": json!("users"),
+                "id": json!("2"),
+                "links": json!({
+                    "self": json!("http://example.com/users/2.json"),
+                }),
+                "attributes": json!({
+                    "username": json!("bob"),
+                }),
+            }),
+        ]),
     });
 
     let actual_json = serde_json::to_string(&document).unwrap();
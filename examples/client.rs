@@ -13,7 +13,7 @@ fn main() {
         .get("/stores", &[("filter[created_by]", "1,3".to_string())])
         .unwrap();
 
-    let document: &Document = response.document();
+    let document: &Document = response.document().unwrap();
 
     println!("{:#?}", document);
 }
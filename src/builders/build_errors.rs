@@ -0,0 +1,212 @@
+use super::*;
+
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BuildErrors(Vec<BuildError>);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuildError {
+    pointer: String,
+    reason: String,
+}
+
+impl BuildErrors {
+    pub fn iter(&self) -> std::slice::Iter<'_, BuildError> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(super) fn add<P: ToString, R: ToString>(
+        &mut self,
+        pointer: P,
+        reason: R,
+    ) {
+        self.0.push(BuildError {
+            pointer: pointer.to_string(),
+            reason: reason.to_string(),
+        });
+    }
+
+    pub(super) fn add_at<N: ToString, R: ToString>(
+        &mut self,
+        token: N,
+        reason: R,
+    ) {
+        self.add(
+            format!("/{}", json_pointer::escape(&token.to_string())),
+            reason,
+        );
+    }
+
+    // Takes the result of a nested builder which is located under the given
+    // reference token, and moves its errors (if any) here.
+    pub(super) fn nest<T, N: ToString>(
+        &mut self,
+        token: N,
+        result: Result<T, BuildErrors>,
+    ) -> Option<T> {
+        match result {
+            Ok(entity) => Some(entity),
+            Err(errors) => {
+                let prefix =
+                    format!("/{}", json_pointer::escape(&token.to_string()));
+
+                for error in errors.0 {
+                    self.0.push(BuildError {
+                        pointer: format!("{}{}", prefix, error.pointer),
+                        reason: error.reason,
+                    });
+                }
+
+                None
+            }
+        }
+    }
+
+    pub(super) fn into_result<T>(self, entity: T) -> Result<T, BuildErrors> {
+        if self.is_empty() {
+            Ok(entity)
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Display for BuildErrors {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        for (index, error) in self.0.iter().enumerate() {
+            if index != 0 {
+                formatter.write_str("; ")?;
+            }
+
+            Display::fmt(error, formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for BuildErrors {}
+
+impl IntoIterator for BuildErrors {
+    type Item = BuildError;
+    type IntoIter = std::vec::IntoIter<BuildError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a BuildErrors {
+    type Item = &'a BuildError;
+    type IntoIter = std::slice::Iter<'a, BuildError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl BuildError {
+    pub fn pointer(&self) -> &str {
+        &self.pointer
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for BuildError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} (at {:?})", self.reason, self.pointer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(pairs: &[(&str, &str)]) -> BuildErrors {
+        let mut errors = BuildErrors::default();
+        for (pointer, reason) in pairs {
+            errors.add(pointer, reason);
+        }
+        errors
+    }
+
+    #[test]
+    fn nest() {
+        let mut actual = errors(&[("", "foo")]);
+
+        assert_eq!(actual.nest("data", Ok::<_, BuildErrors>(123)), Some(123));
+        assert_eq!(
+            actual.nest::<(), _>(
+                0,
+                Err(errors(&[("", "bar"), ("/type", "car")])),
+            ),
+            None,
+        );
+        assert_eq!(
+            actual.nest::<(), _>("a/b~c", Err(errors(&[("", "cdr")]))),
+            None,
+        );
+
+        assert_eq!(
+            actual,
+            errors(&[
+                ("", "foo"),
+                ("/0", "bar"),
+                ("/0/type", "car"),
+                ("/a~1b~0c", "cdr"),
+            ]),
+        );
+    }
+
+    #[test]
+    fn add_at() {
+        let mut actual = BuildErrors::default();
+        actual.add_at("foo", "bar");
+        actual.add_at("a/b~c", "car");
+
+        assert_eq!(actual, errors(&[("/foo", "bar"), ("/a~1b~0c", "car")]));
+    }
+
+    #[test]
+    fn into_result() {
+        assert_eq!(BuildErrors::default().into_result(123), Ok(123));
+        assert_eq!(
+            errors(&[("", "foo")]).into_result(123),
+            Err(errors(&[("", "foo")])),
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(BuildErrors::default().to_string(), "");
+        assert_eq!(
+            errors(&[("", "foo"), ("/data/0/type", "bar")]).to_string(),
+            "foo (at \"\"); bar (at \"/data/0/type\")",
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        let errors = errors(&[("/data", "foo")]);
+
+        assert_eq!(errors.len(), 1);
+        assert!(!errors.is_empty());
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "/data");
+        assert_eq!(error.reason(), "foo");
+    }
+}
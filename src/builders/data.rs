@@ -1,7 +1,11 @@
 use super::*;
 
+// Boxing the single resource would be a breaking change, and newer Clippy
+// warns about the size difference.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataBuilder {
+    Null,
     Single(ResourceBuilder),
     Multiple(Vec<ResourceBuilder>),
 }
@@ -11,14 +15,9 @@ impl Builder<'_> for DataBuilder {
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
         Ok(match self {
+            Self::Null => Data::Null,
             Self::Single(resource) => Data::Single(resource.finish()?),
-            Self::Multiple(resources) => Data::Multiple({
-                let mut new_resources = vec![];
-                for resource in resources {
-                    new_resources.push(resource.finish()?);
-                }
-                new_resources
-            }),
+            Self::Multiple(resources) => Data::Multiple(finish_vec(resources)?),
         })
     }
 }
@@ -26,6 +25,7 @@ impl Builder<'_> for DataBuilder {
 impl From<Data> for DataBuilder {
     fn from(data: Data) -> Self {
         match data {
+            Data::Null => Self::Null,
             Data::Single(resource) => Self::Single(resource.into()),
             Data::Multiple(resources) => Self::Multiple({
                 let mut new_resources = vec![];
@@ -60,6 +60,11 @@ impl<R: Into<ResourceBuilder>> From<Vec<R>> for DataBuilder {
 mod tests {
     use super::*;
 
+    #[test]
+    fn null() {
+        assert_eq!(DataBuilder::Null.unwrap(), Data::Null);
+    }
+
     #[test]
     fn single() {
         assert_eq!(
@@ -67,6 +72,7 @@ mod tests {
             Data::Single(Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
@@ -91,6 +97,7 @@ mod tests {
             Data::Multiple(vec![Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
@@ -111,6 +118,7 @@ mod tests {
                 Resource {
                     type_: "qwerties".into(),
                     id: None,
+                    lid: None,
                     meta: None,
                     links: None,
                     attributes: None,
@@ -119,6 +127,7 @@ mod tests {
                 Resource {
                     type_: "foobars".into(),
                     id: None,
+                    lid: None,
                     meta: None,
                     links: None,
                     attributes: None,
@@ -128,5 +137,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn implicit_from_entity_null() {
+        let builder: DataBuilder = Data::Null.into();
+
+        assert_eq!(builder, DataBuilder::Null);
+        assert_eq!(builder.unwrap(), Data::Null);
+    }
+
     // TODO: 3 or 5 implicit tests
+
+    #[test]
+    fn multiple_invalid() {
+        let errors = DataBuilder::Multiple(vec![
+            ResourceBuilder::new("qwerties"),
+            ResourceBuilder::new("foobars").attr("id", 123),
+        ])
+        .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors.iter().next().unwrap().pointer(), "/1/attributes/id");
+    }
 }
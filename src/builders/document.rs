@@ -7,43 +7,62 @@ pub struct DocumentBuilder {
     links: Option<LinksBuilder>,
     data: Option<DataBuilder>,
     errors: Option<Vec<ErrorObjectBuilder>>,
+    included: Option<Vec<ResourceBuilder>>,
 }
 
 impl Builder<'_> for DocumentBuilder {
     type Entity = Document;
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
-        let errors = match self.errors {
-            None => None,
-            Some(errors) => {
-                let mut new_errors = Vec::new();
+        let mut errors = BuildErrors::default();
 
-                for error_object in errors {
-                    new_errors.push(error_object.finish()?);
-                }
+        if self.data.is_some() && self.errors.is_some() {
+            errors.add_at(
+                "errors",
+                "document must not contain both data and errors",
+            );
+        }
 
-                Some(new_errors)
-            }
-        };
+        if self.data.is_none() && self.included.is_some() {
+            errors.add_at(
+                "included",
+                "document must not contain included without data",
+            );
+        }
 
-        Ok(Self::Entity {
-            jsonapi: match self.jsonapi {
-                None => None,
-                Some(jsonapi) => Some(jsonapi.finish()?),
-            },
-            meta: match self.meta {
-                None => None,
-                Some(meta) => Some(meta.finish()?),
-            },
-            links: match self.links {
-                None => None,
-                Some(links) => Some(links.finish()?),
-            },
-            data: match self.data {
-                None => None,
-                Some(data) => Some(data.finish()?),
-            },
-            errors,
+        if self.data.is_none() && self.errors.is_none() && self.meta.is_none() {
+            errors.add(
+                "",
+                "document must contain at least one of data, errors or meta",
+            );
+        }
+
+        let jsonapi = self
+            .jsonapi
+            .and_then(|jsonapi| errors.nest("jsonapi", jsonapi.finish()));
+        let meta = self
+            .meta
+            .and_then(|meta| errors.nest("meta", meta.finish()));
+        let links = self
+            .links
+            .and_then(|links| errors.nest("links", links.finish()));
+        let data = self
+            .data
+            .and_then(|data| errors.nest("data", data.finish()));
+        let error_objects = self.errors.and_then(|error_objects| {
+            errors.nest("errors", finish_vec(error_objects))
+        });
+        let included = self
+            .included
+            .and_then(|included| errors.nest("included", finish_vec(included)));
+
+        errors.into_result(Self::Entity {
+            jsonapi,
+            meta,
+            links,
+            data,
+            errors: error_objects,
+            included,
         })
     }
 }
@@ -90,6 +109,19 @@ impl DocumentBuilder {
         }
     }
 
+    pub fn included<R: Into<ResourceBuilder>>(self, included: Vec<R>) -> Self {
+        let mut new_included = Vec::new();
+
+        for resource in included {
+            new_included.push(resource.into());
+        }
+
+        Self {
+            included: Some(new_included),
+            ..self
+        }
+    }
+
     pub fn meta1<N: ToString, V: Into<Value>>(self, name: N, meta1: V) -> Self {
         let meta = self.meta.unwrap_or_default().item(name, meta1);
 
@@ -121,6 +153,16 @@ impl DocumentBuilder {
             ..self
         }
     }
+
+    pub fn include<R: Into<ResourceBuilder>>(self, resource: R) -> Self {
+        let mut included = self.included.unwrap_or_default();
+        included.push(resource.into());
+
+        Self {
+            included: Some(included),
+            ..self
+        }
+    }
 }
 
 impl From<Document> for DocumentBuilder {
@@ -138,12 +180,26 @@ impl From<Document> for DocumentBuilder {
             }
         };
 
+        let included = match document.included {
+            None => None,
+            Some(included) => {
+                let mut new_included = Vec::new();
+
+                for resource in included {
+                    new_included.push(resource.into());
+                }
+
+                Some(new_included)
+            }
+        };
+
         Self {
             jsonapi: document.jsonapi.map(|jsonapi| jsonapi.into()),
             meta: document.meta.map(|meta| meta.into()),
             links: document.links.map(|links| links.into()),
             data: document.data.map(|data| data.into()),
             errors,
+            included,
         }
     }
 }
@@ -155,15 +211,16 @@ mod tests {
 
     #[test]
     fn empty() {
+        let errors = DocumentBuilder::default().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "");
         assert_eq!(
-            DocumentBuilder::default().unwrap(),
-            Document {
-                jsonapi: None,
-                meta: None,
-                links: None,
-                data: None,
-                errors: None,
-            },
+            error.reason(),
+            "document must contain at least one of data, errors or meta",
         );
     }
 
@@ -183,7 +240,7 @@ mod tests {
                         .link("qwe", LinkBuilder::new("http://qwe.com")),
                 )
                 .data(DataBuilder::Single(ResourceBuilder::new("qwerties")))
-                .errors(vec![ErrorObjectBuilder::default().id("789")])
+                .included(vec![ResourceBuilder::new_with_id("qwerties", "456")])
                 .unwrap(),
             Document {
                 jsonapi: Some(JsonApi {
@@ -195,12 +252,22 @@ mod tests {
                 data: Some(Data::Single(Resource {
                     type_: "qwerties".into(),
                     id: None,
+                    lid: None,
                     meta: None,
                     links: None,
                     attributes: None,
                     relationships: None,
                 })),
-                errors: Some(fixtures::simple_errors()),
+                errors: None,
+                included: Some(vec![Resource {
+                    type_: "qwerties".into(),
+                    id: Some("456".into()),
+                    lid: None,
+                    meta: None,
+                    links: None,
+                    attributes: None,
+                    relationships: None,
+                }]),
             },
         );
     }
@@ -215,7 +282,7 @@ mod tests {
                 .link("self", LinkBuilder::new("http://self.com"))
                 .link("qwe", LinkBuilder::new("http://qwe.com"))
                 .data(DataBuilder::Single(ResourceBuilder::new("qwerties")))
-                .error(ErrorObjectBuilder::default().id("789"))
+                .include(ResourceBuilder::new_with_id("qwerties", "456"))
                 .unwrap(),
             Document {
                 jsonapi: Some(JsonApi {
@@ -227,12 +294,22 @@ mod tests {
                 data: Some(Data::Single(Resource {
                     type_: "qwerties".into(),
                     id: None,
+                    lid: None,
                     meta: None,
                     links: None,
                     attributes: None,
                     relationships: None,
                 })),
-                errors: Some(fixtures::simple_errors()),
+                errors: None,
+                included: Some(vec![Resource {
+                    type_: "qwerties".into(),
+                    id: Some("456".into()),
+                    lid: None,
+                    meta: None,
+                    links: None,
+                    attributes: None,
+                    relationships: None,
+                }]),
             },
         );
     }
@@ -242,6 +319,7 @@ mod tests {
         assert_eq!(
             DocumentBuilder::default()
                 .jsonapi(JsonApiBuilder::default().version(Version::new(456)))
+                .data(DataBuilder::Null)
                 .unwrap(),
             Document {
                 jsonapi: Some(JsonApi {
@@ -250,8 +328,9 @@ mod tests {
                 }),
                 meta: None,
                 links: None,
-                data: None,
+                data: Some(Data::Null),
                 errors: None,
+                included: None,
             },
         );
     }
@@ -261,6 +340,7 @@ mod tests {
         assert_eq!(
             DocumentBuilder::default()
                 .jsonapi(Version::new(456))
+                .data(DataBuilder::Null)
                 .unwrap(),
             Document {
                 jsonapi: Some(JsonApi {
@@ -269,8 +349,9 @@ mod tests {
                 }),
                 meta: None,
                 links: None,
-                data: None,
+                data: Some(Data::Null),
                 errors: None,
+                included: None,
             },
         );
     }
@@ -291,6 +372,7 @@ mod tests {
                 links: None,
                 data: None,
                 errors: None,
+                included: None,
             },
         );
     }
@@ -310,12 +392,13 @@ mod tests {
                             ),
                         ),
                 )
+                .data(DataBuilder::Null)
                 .unwrap(),
             Document {
                 jsonapi: None,
                 meta: None,
                 links: Some(Links {
-                    other: HashMap::new(),
+                    other: Map::new(),
                     self_: Some(Link::String("http://self.com".into())),
                     related: None,
                     first: None,
@@ -327,8 +410,24 @@ mod tests {
                     next: None,
                     about: None,
                 }),
-                data: None,
+                data: Some(Data::Null),
                 errors: None,
+                included: None,
+            },
+        );
+    }
+
+    #[test]
+    fn with_data_null() {
+        assert_eq!(
+            DocumentBuilder::default().data(DataBuilder::Null).unwrap(),
+            Document {
+                jsonapi: None,
+                meta: None,
+                links: None,
+                data: Some(Data::Null),
+                errors: None,
+                included: None,
             },
         );
     }
@@ -348,12 +447,14 @@ mod tests {
                 data: Some(Data::Multiple(vec![Resource {
                     type_: "qwerties".into(),
                     id: None,
+                    lid: None,
                     meta: None,
                     links: None,
                     attributes: None,
                     relationships: None,
                 }])),
                 errors: None,
+                included: None,
             },
         );
     }
@@ -371,12 +472,14 @@ mod tests {
                 data: Some(Data::Single(Resource {
                     type_: "qwerties".into(),
                     id: None,
+                    lid: None,
                     meta: None,
                     links: None,
                     attributes: None,
                     relationships: None,
                 })),
                 errors: None,
+                included: None,
             },
         );
     }
@@ -394,12 +497,14 @@ mod tests {
                 data: Some(Data::Multiple(vec![Resource {
                     type_: "qwerties".into(),
                     id: None,
+                    lid: None,
                     meta: None,
                     links: None,
                     attributes: None,
                     relationships: None,
                 }])),
                 errors: None,
+                included: None,
             },
         );
     }
@@ -416,10 +521,89 @@ mod tests {
                 links: None,
                 data: None,
                 errors: Some(fixtures::simple_errors()),
+                included: None,
+            },
+        );
+    }
+
+    #[test]
+    fn with_error_implicit() {
+        assert_eq!(
+            DocumentBuilder::default()
+                .error(ErrorObjectBuilder::default().id("789"))
+                .unwrap(),
+            DocumentBuilder::default()
+                .errors(vec![ErrorObjectBuilder::default().id("789")])
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn with_included() {
+        assert_eq!(
+            DocumentBuilder::default()
+                .data(ResourceBuilder::new_with_id("qwerties", "123"))
+                .included(vec![
+                    ResourceBuilder::new_with_id("qwerties", "456"),
+                    ResourceBuilder::new_with_id("foobars", "789"),
+                ])
+                .unwrap(),
+            Document {
+                jsonapi: None,
+                meta: None,
+                links: None,
+                data: Some(Data::Single(Resource {
+                    type_: "qwerties".into(),
+                    id: Some("123".into()),
+                    lid: None,
+                    meta: None,
+                    links: None,
+                    attributes: None,
+                    relationships: None,
+                })),
+                errors: None,
+                included: Some(vec![
+                    Resource {
+                        type_: "qwerties".into(),
+                        id: Some("456".into()),
+                        lid: None,
+                        meta: None,
+                        links: None,
+                        attributes: None,
+                        relationships: None,
+                    },
+                    Resource {
+                        type_: "foobars".into(),
+                        id: Some("789".into()),
+                        lid: None,
+                        meta: None,
+                        links: None,
+                        attributes: None,
+                        relationships: None,
+                    },
+                ]),
             },
         );
     }
 
+    #[test]
+    fn with_include_implicit() {
+        assert_eq!(
+            DocumentBuilder::default()
+                .data(ResourceBuilder::new_with_id("qwerties", "123"))
+                .include(ResourceBuilder::new_with_id("qwerties", "456"))
+                .include(ResourceBuilder::new_with_id("foobars", "789"))
+                .unwrap(),
+            DocumentBuilder::default()
+                .data(ResourceBuilder::new_with_id("qwerties", "123"))
+                .included(vec![
+                    ResourceBuilder::new_with_id("qwerties", "456"),
+                    ResourceBuilder::new_with_id("foobars", "789"),
+                ])
+                .unwrap(),
+        );
+    }
+
     #[test]
     fn with_meta1_implicit() {
         assert_eq!(
@@ -438,6 +622,7 @@ mod tests {
                 links: None,
                 data: None,
                 errors: None,
+                included: None,
             },
         );
     }
@@ -448,13 +633,14 @@ mod tests {
             DocumentBuilder::default()
                 .link("self", "http://self.com")
                 .link("foo", "http://foo.com")
+                .data(DataBuilder::Null)
                 .unwrap(),
             Document {
                 jsonapi: None,
                 meta: None,
                 links: Some(Links {
                     other: {
-                        let mut other = HashMap::new();
+                        let mut other = Map::new();
                         other.insert(
                             "foo".into(),
                             Link::String("http://foo.com".into()),
@@ -469,8 +655,9 @@ mod tests {
                     next: None,
                     about: None,
                 }),
-                data: None,
+                data: Some(Data::Null),
                 errors: None,
+                included: None,
             },
         );
     }
@@ -487,12 +674,22 @@ mod tests {
             data: Some(Data::Single(Resource {
                 type_: "qwerties".into(),
                 id: Some("123".into()),
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
                 relationships: None,
             })),
-            errors: Some(fixtures::simple_errors()),
+            errors: None,
+            included: Some(vec![Resource {
+                type_: "qwerties".into(),
+                id: Some("456".into()),
+                lid: None,
+                meta: None,
+                links: None,
+                attributes: None,
+                relationships: None,
+            }]),
         };
 
         let builder: DocumentBuilder = document.clone().into();
@@ -512,6 +709,7 @@ mod tests {
                 links: None,
                 data: None,
                 errors: None,
+                included: None,
             },
         );
     }
@@ -521,13 +719,15 @@ mod tests {
         assert_eq!(
             DocumentBuilder::default()
                 .links(fixtures::simple_links())
+                .data(DataBuilder::Null)
                 .unwrap(),
             Document {
                 jsonapi: None,
                 meta: None,
                 links: Some(fixtures::simple_links()),
-                data: None,
+                data: Some(Data::Null),
                 errors: None,
+                included: None,
             },
         );
     }
@@ -544,7 +744,88 @@ mod tests {
                 links: None,
                 data: None,
                 errors: Some(fixtures::simple_errors()),
+                included: None,
             },
         );
     }
+
+    #[test]
+    fn invalid_nested() {
+        let errors = DocumentBuilder::default()
+            .data(vec![
+                ResourceBuilder::new_with_id("articles", "1")
+                    .rel("author", ResourceIdentifierBuilder::new("people", 9)),
+                ResourceBuilder::new_with_id("articles", "2")
+                    .rel("author", RelationshipBuilder::default()),
+            ])
+            .included(vec![
+                ResourceBuilder::new_with_id("people", "9"),
+                ResourceBuilder::new_with_id("people", "10").meta1("a/b", 1),
+            ])
+            .unwrap_err();
+
+        let errors: Vec<(&str, &str)> = errors
+            .iter()
+            .map(|error| (error.pointer(), error.reason()))
+            .collect();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "/data/1/relationships/author",
+                    "relationship must contain at least one of links, data \
+                        or meta",
+                ),
+                ("/included/1/meta/a~1b", "invalid member name"),
+            ],
+        );
+    }
+
+    #[test]
+    fn data_with_errors() {
+        let errors = DocumentBuilder::default()
+            .data(ResourceBuilder::new_with_id("qwerties", "123"))
+            .error(ErrorObjectBuilder::default().id("789"))
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "/errors");
+        assert_eq!(
+            error.reason(),
+            "document must not contain both data and errors",
+        );
+    }
+
+    #[test]
+    fn included_without_data() {
+        let errors = DocumentBuilder::default()
+            .meta1("foo", 123)
+            .include(ResourceBuilder::new_with_id("qwerties", "456"))
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "/included");
+        assert_eq!(
+            error.reason(),
+            "document must not contain included without data",
+        );
+    }
+
+    #[test]
+    fn included_with_errors() {
+        let errors = DocumentBuilder::default()
+            .error(ErrorObjectBuilder::default().id("789"))
+            .include(ResourceBuilder::new_with_id("qwerties", "456"))
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors.iter().next().unwrap().pointer(), "/included");
+    }
 }
@@ -16,24 +16,27 @@ impl Builder<'_> for ErrorObjectBuilder {
     type Entity = ErrorObject;
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
-        Ok(Self::Entity {
+        let mut errors = BuildErrors::default();
+
+        let links = self
+            .links
+            .and_then(|links| errors.nest("links", links.finish()));
+        let source = self
+            .source
+            .and_then(|source| errors.nest("source", source.finish()));
+        let meta = self
+            .meta
+            .and_then(|meta| errors.nest("meta", meta.finish()));
+
+        errors.into_result(Self::Entity {
             id: self.id,
-            links: match self.links {
-                None => None,
-                Some(links) => Some(links.finish()?),
-            },
+            links,
             status: self.status,
             code: self.code,
             title: self.title,
             detail: self.detail,
-            source: match self.source {
-                None => None,
-                Some(source) => Some(source.finish()?),
-            },
-            meta: match self.meta {
-                None => None,
-                Some(meta) => Some(meta.finish()?),
-            },
+            source,
+            meta,
         })
     }
 }
@@ -245,4 +248,18 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn invalid_nested() {
+        let errors = ErrorObjectBuilder::default()
+            .pointer("foo/0")
+            .meta1("foo/", 123)
+            .unwrap_err();
+
+        let mut pointers: Vec<&str> =
+            errors.iter().map(|error| error.pointer()).collect();
+        pointers.sort_unstable();
+
+        assert_eq!(pointers, vec!["/meta/foo~1", "/source/pointer"]);
+    }
 }
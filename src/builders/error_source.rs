@@ -10,7 +10,15 @@ impl Builder<'_> for ErrorSourceBuilder {
     type Entity = ErrorSource;
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
-        Ok(Self::Entity {
+        let mut errors = BuildErrors::default();
+
+        if let Some(pointer) = &self.pointer {
+            if !json_pointer::is_valid(pointer) {
+                errors.add_at("pointer", "invalid JSON pointer");
+            }
+        }
+
+        errors.into_result(Self::Entity {
             pointer: self.pointer,
             parameter: self.parameter,
         })
@@ -106,4 +114,17 @@ mod tests {
 
         assert_eq!(builder.unwrap(), error_source);
     }
+
+    #[test]
+    fn invalid_pointer() {
+        let errors =
+            ErrorSourceBuilder::default().pointer("foo/0").unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "/pointer");
+        assert_eq!(error.reason(), "invalid JSON pointer");
+    }
 }
@@ -0,0 +1,164 @@
+use super::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IdentifierDataBuilder {
+    Null,
+    Single(ResourceIdentifierBuilder),
+    Multiple(Vec<ResourceIdentifierBuilder>),
+}
+
+impl Builder<'_> for IdentifierDataBuilder {
+    type Entity = IdentifierData;
+
+    fn finish(self) -> Result<Self::Entity, BuildErrors> {
+        Ok(match self {
+            Self::Null => IdentifierData::Null,
+            Self::Single(resource_identifier) => {
+                IdentifierData::Single(resource_identifier.finish()?)
+            }
+            Self::Multiple(resource_identifiers) => {
+                IdentifierData::Multiple(finish_vec(resource_identifiers)?)
+            }
+        })
+    }
+}
+
+impl From<IdentifierData> for IdentifierDataBuilder {
+    fn from(identifier_data: IdentifierData) -> Self {
+        match identifier_data {
+            IdentifierData::Null => Self::Null,
+            IdentifierData::Single(resource_identifier) => {
+                Self::Single(resource_identifier.into())
+            }
+            IdentifierData::Multiple(resource_identifiers) => Self::Multiple({
+                let mut new_resource_identifiers = vec![];
+                for resource_identifier in resource_identifiers {
+                    new_resource_identifiers.push(resource_identifier.into());
+                }
+                new_resource_identifiers
+            }),
+        }
+    }
+}
+
+impl<R: Into<ResourceIdentifierBuilder>> From<R> for IdentifierDataBuilder {
+    fn from(resource_identifier: R) -> Self {
+        Self::Single(resource_identifier.into())
+    }
+}
+
+impl<R: Into<ResourceIdentifierBuilder>> From<Vec<R>>
+    for IdentifierDataBuilder
+{
+    fn from(resource_identifiers: Vec<R>) -> Self {
+        Self::Multiple({
+            let mut new_resource_identifiers = vec![];
+            for resource_identifier in resource_identifiers {
+                new_resource_identifiers.push(resource_identifier.into());
+            }
+            new_resource_identifiers
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn null() {
+        assert_eq!(IdentifierDataBuilder::Null.unwrap(), IdentifierData::Null);
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(
+            IdentifierDataBuilder::Single(ResourceIdentifierBuilder::new(
+                "qwerties", 123,
+            ))
+            .unwrap(),
+            IdentifierData::Single(ResourceIdentifier {
+                type_: "qwerties".into(),
+                id: Some("123".into()),
+                lid: None,
+                meta: None,
+            }),
+        );
+    }
+
+    #[test]
+    fn multiple_zero() {
+        assert_eq!(
+            IdentifierDataBuilder::Multiple(vec![]).unwrap(),
+            IdentifierData::Multiple(vec![]),
+        );
+    }
+
+    #[test]
+    fn multiple_two() {
+        assert_eq!(
+            IdentifierDataBuilder::Multiple(vec![
+                ResourceIdentifierBuilder::new("qwerties", 123),
+                ResourceIdentifierBuilder::new_with_lid("foobars", "local-1"),
+            ])
+            .unwrap(),
+            IdentifierData::Multiple(vec![
+                ResourceIdentifier {
+                    type_: "qwerties".into(),
+                    id: Some("123".into()),
+                    lid: None,
+                    meta: None,
+                },
+                ResourceIdentifier {
+                    type_: "foobars".into(),
+                    id: None,
+                    lid: Some("local-1".into()),
+                    meta: None,
+                },
+            ]),
+        );
+    }
+
+    #[test]
+    fn implicit_from_resource_identifiers() {
+        let builder: IdentifierDataBuilder = vec![
+            ResourceIdentifierBuilder::new("qwerties", 123),
+            ResourceIdentifierBuilder::new("qwerties", 456),
+        ]
+        .into();
+
+        assert_eq!(
+            builder,
+            IdentifierDataBuilder::Multiple(vec![
+                ResourceIdentifierBuilder::new("qwerties", 123),
+                ResourceIdentifierBuilder::new("qwerties", 456),
+            ]),
+        );
+    }
+
+    #[test]
+    fn implicit_from_entity() {
+        let identifier_data = IdentifierData::Single(ResourceIdentifier {
+            type_: "qwerties".into(),
+            id: Some("123".into()),
+            lid: None,
+            meta: None,
+        });
+
+        let builder: IdentifierDataBuilder = identifier_data.clone().into();
+
+        assert_eq!(builder.unwrap(), identifier_data);
+    }
+
+    #[test]
+    fn multiple_invalid() {
+        let errors = IdentifierDataBuilder::Multiple(vec![
+            ResourceIdentifierBuilder::new("qwerties", 123),
+            ResourceIdentifierBuilder::new("qwer ties ", 456),
+        ])
+        .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors.iter().next().unwrap().pointer(), "/1/type");
+    }
+}
@@ -10,12 +10,15 @@ impl Builder<'_> for JsonApiBuilder {
     type Entity = JsonApi;
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
-        Ok(Self::Entity {
+        let mut errors = BuildErrors::default();
+
+        let meta = self
+            .meta
+            .and_then(|meta| errors.nest("meta", meta.finish()));
+
+        errors.into_result(Self::Entity {
             version: self.version,
-            meta: match self.meta {
-                None => None,
-                Some(meta) => Some(meta.finish()?),
-            },
+            meta,
         })
     }
 }
@@ -142,4 +145,14 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn invalid_meta() {
+        let errors = JsonApiBuilder::default()
+            .meta(MetaOrAttrsBuilder::default().item("foo.bar", 123))
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors.iter().next().unwrap().pointer(), "/meta/foo.bar");
+    }
 }
@@ -19,13 +19,17 @@ impl Builder<'_> for LinkBuilder {
     type Entity = Link;
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
-        Ok(match self.meta {
+        let mut errors = BuildErrors::default();
+
+        let link = match self.meta {
             None => Link::String(self.href),
             Some(meta) => Link::Object(LinkObject {
                 href: self.href,
-                meta: Some(meta.finish()?),
+                meta: errors.nest("meta", meta.finish()),
             }),
-        })
+        };
+
+        errors.into_result(link)
     }
 }
 
@@ -125,4 +129,14 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn invalid_meta() {
+        let errors = LinkBuilder::new("http://example.com")
+            .meta(MetaOrAttrsBuilder::default().item("", 123))
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors.iter().next().unwrap().pointer(), "/meta/");
+    }
 }
@@ -2,7 +2,7 @@ use super::*;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct LinksBuilder {
-    pub other: HashMap<String, LinkBuilder>,
+    pub other: Map<String, LinkBuilder>,
     // Basic (https://jsonapi.org/format/#document-links)
     pub self_: Option<LinkBuilder>,
     pub related: Option<LinkBuilder>,
@@ -19,45 +19,56 @@ impl Builder<'_> for LinksBuilder {
     type Entity = Links;
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
-        let mut other = HashMap::new();
+        let mut errors = BuildErrors::default();
+        let mut other = Map::new();
 
         for (key, value) in self.other {
-            other.insert(key, value.finish()?);
+            if !member_name::is_valid(&key) {
+                errors.add_at(&key, "invalid member name");
+            }
+
+            if let Some(value) = errors.nest(&key, value.finish()) {
+                other.insert(key, value);
+            }
         }
 
-        Ok(Self::Entity {
+        // Basic
+        let self_ = self
+            .self_
+            .and_then(|self_| errors.nest("self", self_.finish()));
+        let related = self
+            .related
+            .and_then(|related| errors.nest("related", related.finish()));
+        // Pagination
+        let first = self
+            .first
+            .and_then(|first| errors.nest("first", first.finish()));
+        let last = self
+            .last
+            .and_then(|last| errors.nest("last", last.finish()));
+        let prev = self
+            .prev
+            .and_then(|prev| errors.nest("prev", prev.finish()));
+        let next = self
+            .next
+            .and_then(|next| errors.nest("next", next.finish()));
+        // Errors
+        let about = self
+            .about
+            .and_then(|about| errors.nest("about", about.finish()));
+
+        errors.into_result(Self::Entity {
             other,
             // Basic
-            self_: match self.self_ {
-                None => None,
-                Some(self_) => Some(self_.finish()?),
-            },
-            related: match self.related {
-                None => None,
-                Some(related) => Some(related.finish()?),
-            },
+            self_,
+            related,
             // Pagination
-            first: match self.first {
-                None => None,
-                Some(first) => Some(first.finish()?),
-            },
-            last: match self.last {
-                None => None,
-                Some(last) => Some(last.finish()?),
-            },
-            prev: match self.prev {
-                None => None,
-                Some(prev) => Some(prev.finish()?),
-            },
-            next: match self.next {
-                None => None,
-                Some(next) => Some(next.finish()?),
-            },
+            first,
+            last,
+            prev,
+            next,
             // Errors
-            about: match self.about {
-                None => None,
-                Some(about) => Some(about.finish()?),
-            },
+            about,
         })
     }
 }
@@ -195,7 +206,7 @@ impl From<Links> for LinksBuilder {
     fn from(links: Links) -> Self {
         Self {
             other: {
-                let mut other = HashMap::new();
+                let mut other = Map::new();
                 for (key, value) in links.other {
                     other.insert(key, value.into());
                 }
@@ -225,7 +236,7 @@ mod tests {
         assert_eq!(
             LinksBuilder::default().unwrap(),
             Links {
-                other: HashMap::new(),
+                other: Map::new(),
                 self_: None,
                 related: None,
                 first: None,
@@ -266,7 +277,7 @@ mod tests {
                 .unwrap(),
             Links {
                 other: {
-                    let mut other = HashMap::new();
+                    let mut other = Map::new();
                     other.insert(
                         "foo".into(),
                         Link::String("http://foo.com".into()),
@@ -316,7 +327,7 @@ mod tests {
                 .unwrap(),
             Links {
                 other: {
-                    let mut other = HashMap::new();
+                    let mut other = Map::new();
                     other.insert(
                         "foo".into(),
                         Link::String("http://foo.com".into()),
@@ -353,7 +364,7 @@ mod tests {
                 .unwrap(),
             Links {
                 other: {
-                    let mut other = HashMap::new();
+                    let mut other = Map::new();
                     other.insert(
                         "foo".into(),
                         Link::String("http://foo.com".into()),
@@ -379,7 +390,7 @@ mod tests {
     fn implicit_from_entity() {
         let links = Links {
             other: {
-                let mut other = HashMap::new();
+                let mut other = Map::new();
                 other.insert(
                     "foo".into(),
                     Link::String("http://foo.com".into()),
@@ -399,4 +410,32 @@ mod tests {
 
         assert_eq!(builder.unwrap(), links);
     }
+
+    #[test]
+    fn invalid_member_name() {
+        let errors = LinksBuilder::default()
+            .self_("http://self.com")
+            .link("foo[bar]", "http://foo.com")
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "/foo[bar]");
+        assert_eq!(error.reason(), "invalid member name");
+    }
+
+    #[test]
+    fn invalid_nested() {
+        let errors = LinksBuilder::default()
+            .next(
+                LinkBuilder::new("http://next.com")
+                    .meta(MetaOrAttrsBuilder::default().item("-", 123)),
+            )
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors.iter().next().unwrap().pointer(), "/next/meta/-");
+    }
 }
@@ -7,7 +7,15 @@ impl Builder<'_> for MetaOrAttrsBuilder {
     type Entity = MetaOrAttrs;
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
-        Ok(self.0)
+        let mut errors = BuildErrors::default();
+
+        for name in self.0.keys() {
+            if !member_name::is_valid(name) {
+                errors.add_at(name, "invalid member name");
+            }
+        }
+
+        errors.into_result(self.0)
     }
 }
 
@@ -41,7 +49,7 @@ mod tests {
                 .item("false", false)
                 .item("true", true)
                 .item("int", 123)
-                .item("float", 3.1415926536)
+                .item("float", 123.456)
                 .item("str", "Hello, World!")
                 .item("array", vec![1, 2, 3])
                 .item("object", {
@@ -58,7 +66,7 @@ mod tests {
                 meta_or_attrs.insert(
                     "float".into(),
                     Value::Number(
-                        serde_json::Number::from_f64(3.1415926536).unwrap(),
+                        serde_json::Number::from_f64(123.456).unwrap(),
                     ),
                 );
                 meta_or_attrs.insert(
@@ -94,4 +102,19 @@ mod tests {
 
         assert_eq!(builder.unwrap(), meta_or_attrs);
     }
+
+    #[test]
+    fn invalid_member_name() {
+        let errors = MetaOrAttrsBuilder::default()
+            .item("foo", 123)
+            .item("-foo", 456)
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "/-foo");
+        assert_eq!(error.reason(), "invalid member name");
+    }
 }
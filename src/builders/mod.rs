@@ -1,7 +1,9 @@
+mod build_errors;
 mod data;
 mod document;
 mod error_object;
 mod error_source;
+mod identifier_data;
 mod jsonapi;
 mod link;
 mod links;
@@ -9,11 +11,14 @@ mod meta_or_attrs;
 mod relationship;
 mod relationships;
 mod resource;
+mod resource_identifier;
 
+pub use build_errors::{BuildError, BuildErrors};
 pub use data::DataBuilder;
 pub use document::DocumentBuilder;
 pub use error_object::ErrorObjectBuilder;
 pub use error_source::ErrorSourceBuilder;
+pub use identifier_data::IdentifierDataBuilder;
 pub use jsonapi::JsonApiBuilder;
 pub use link::LinkBuilder;
 pub use links::LinksBuilder;
@@ -21,16 +26,29 @@ pub use meta_or_attrs::MetaOrAttrsBuilder;
 pub use relationship::RelationshipBuilder;
 pub use relationships::RelationshipsBuilder;
 pub use resource::ResourceBuilder;
+pub use resource_identifier::ResourceIdentifierBuilder;
 
 use super::entities::*;
+use super::{json_pointer, member_name};
 
-use std::collections::HashMap;
 use std::fmt::Debug;
 
 use serde_json::Value;
 
-#[derive(Debug)]
-pub struct BuildErrors;
+fn finish_vec<'de, B: Builder<'de>>(
+    builders: Vec<B>,
+) -> Result<Vec<B::Entity>, BuildErrors> {
+    let mut errors = BuildErrors::default();
+    let mut entities = Vec::new();
+
+    for (index, builder) in builders.into_iter().enumerate() {
+        if let Some(entity) = errors.nest(index, builder.finish()) {
+            entities.push(entity);
+        }
+    }
+
+    errors.into_result(entities)
+}
 
 pub trait Builder<'de>: Clone + Debug + Eq + PartialEq + Sized {
     type Entity: Entity<'de>;
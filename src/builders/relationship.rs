@@ -4,27 +4,33 @@ use super::*;
 pub struct RelationshipBuilder {
     meta: Option<MetaOrAttrsBuilder>,
     links: Option<LinksBuilder>,
-    data: Option<DataBuilder>,
+    data: Option<IdentifierDataBuilder>,
 }
 
 impl Builder<'_> for RelationshipBuilder {
     type Entity = Relationship;
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
-        Ok(Self::Entity {
-            meta: match self.meta {
-                None => None,
-                Some(meta) => Some(meta.finish()?),
-            },
-            links: match self.links {
-                None => None,
-                Some(links) => Some(links.finish()?),
-            },
-            data: match self.data {
-                None => None,
-                Some(data) => Some(data.finish()?),
-            },
-        })
+        let mut errors = BuildErrors::default();
+
+        if self.meta.is_none() && self.links.is_none() && self.data.is_none() {
+            errors.add(
+                "",
+                "relationship must contain at least one of links, data or meta",
+            );
+        }
+
+        let meta = self
+            .meta
+            .and_then(|meta| errors.nest("meta", meta.finish()));
+        let links = self
+            .links
+            .and_then(|links| errors.nest("links", links.finish()));
+        let data = self
+            .data
+            .and_then(|data| errors.nest("data", data.finish()));
+
+        errors.into_result(Self::Entity { meta, links, data })
     }
 }
 
@@ -43,7 +49,7 @@ impl RelationshipBuilder {
         }
     }
 
-    pub fn data<D: Into<DataBuilder>>(self, data: D) -> Self {
+    pub fn data<D: Into<IdentifierDataBuilder>>(self, data: D) -> Self {
         Self {
             data: Some(data.into()),
             ..self
@@ -83,9 +89,9 @@ impl From<Relationship> for RelationshipBuilder {
     }
 }
 
-impl<R: Into<ResourceBuilder>> From<R> for RelationshipBuilder {
-    fn from(resource: R) -> Self {
-        Self::default().data(resource.into())
+impl<R: Into<ResourceIdentifierBuilder>> From<R> for RelationshipBuilder {
+    fn from(resource_identifier: R) -> Self {
+        Self::default().data(resource_identifier.into())
     }
 }
 
@@ -96,13 +102,16 @@ mod tests {
 
     #[test]
     fn empty() {
+        let errors = RelationshipBuilder::default().unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "");
         assert_eq!(
-            RelationshipBuilder::default().unwrap(),
-            Relationship {
-                meta: None,
-                links: None,
-                data: None,
-            },
+            error.reason(),
+            "relationship must contain at least one of links, data or meta",
         );
     }
 
@@ -126,12 +135,14 @@ mod tests {
                             ),
                         ),
                 )
-                .data(DataBuilder::Single(ResourceBuilder::new("qwerties")))
+                .data(IdentifierDataBuilder::Single(
+                    ResourceIdentifierBuilder::new("qwerties", "123",)
+                ))
                 .unwrap(),
             Relationship {
                 meta: Some(fixtures::meta_or_attrs()),
                 links: Some(Links {
-                    other: HashMap::new(),
+                    other: Map::new(),
                     self_: Some(Link::String("http://self.com".into())),
                     related: None,
                     first: None,
@@ -143,13 +154,11 @@ mod tests {
                     next: None,
                     about: None,
                 }),
-                data: Some(Data::Single(Resource {
+                data: Some(IdentifierData::Single(ResourceIdentifier {
                     type_: "qwerties".into(),
-                    id: None,
+                    id: Some("123".into()),
+                    lid: None,
                     meta: None,
-                    links: None,
-                    attributes: None,
-                    relationships: None,
                 })),
             },
         );
@@ -170,12 +179,14 @@ mod tests {
                             .item("bar", "qwe"),
                     )
                 )
-                .data(DataBuilder::Single(ResourceBuilder::new("qwerties")))
+                .data(IdentifierDataBuilder::Single(
+                    ResourceIdentifierBuilder::new("qwerties", "123",)
+                ))
                 .unwrap(),
             Relationship {
                 meta: Some(fixtures::meta_or_attrs()),
                 links: Some(Links {
-                    other: HashMap::new(),
+                    other: Map::new(),
                     self_: Some(Link::String("http://self.com".into())),
                     related: None,
                     first: None,
@@ -187,13 +198,11 @@ mod tests {
                     next: None,
                     about: None,
                 }),
-                data: Some(Data::Single(Resource {
+                data: Some(IdentifierData::Single(ResourceIdentifier {
                     type_: "qwerties".into(),
-                    id: None,
+                    id: Some("123".into()),
+                    lid: None,
                     meta: None,
-                    links: None,
-                    attributes: None,
-                    relationships: None,
                 })),
             },
         );
@@ -203,18 +212,16 @@ mod tests {
     fn with_data_from_resource() {
         assert_eq!(
             RelationshipBuilder::default()
-                .data(ResourceBuilder::new("qwerties"))
+                .data(ResourceIdentifierBuilder::new("qwerties", "123"))
                 .unwrap(),
             Relationship {
                 meta: None,
                 links: None,
-                data: Some(Data::Single(Resource {
+                data: Some(IdentifierData::Single(ResourceIdentifier {
                     type_: "qwerties".into(),
-                    id: None,
+                    id: Some("123".into()),
+                    lid: None,
                     meta: None,
-                    links: None,
-                    attributes: None,
-                    relationships: None,
                 })),
             },
         );
@@ -224,19 +231,19 @@ mod tests {
     fn with_data_from_resources() {
         assert_eq!(
             RelationshipBuilder::default()
-                .data(vec![ResourceBuilder::new("qwerties")])
+                .data(vec![ResourceIdentifierBuilder::new("qwerties", "123")])
                 .unwrap(),
             Relationship {
                 meta: None,
                 links: None,
-                data: Some(Data::Multiple(vec![Resource {
-                    type_: "qwerties".into(),
-                    id: None,
-                    meta: None,
-                    links: None,
-                    attributes: None,
-                    relationships: None,
-                }])),
+                data: Some(IdentifierData::Multiple(vec![
+                    ResourceIdentifier {
+                        type_: "qwerties".into(),
+                        id: Some("123".into()),
+                        lid: None,
+                        meta: None,
+                    }
+                ])),
             },
         );
     }
@@ -281,13 +288,11 @@ mod tests {
         let relationship = Relationship {
             meta: Some(fixtures::meta_or_attrs()),
             links: Some(fixtures::simple_links()),
-            data: Some(Data::Single(Resource {
+            data: Some(IdentifierData::Single(ResourceIdentifier {
                 type_: "qwerties".into(),
                 id: Some("123".into()),
+                lid: None,
                 meta: None,
-                links: None,
-                attributes: None,
-                relationships: None,
             })),
         };
 
@@ -296,6 +301,20 @@ mod tests {
         assert_eq!(builder.unwrap(), relationship);
     }
 
+    #[test]
+    fn with_data_null() {
+        assert_eq!(
+            RelationshipBuilder::default()
+                .data(IdentifierDataBuilder::Null)
+                .unwrap(),
+            Relationship {
+                meta: None,
+                links: None,
+                data: Some(IdentifierData::Null),
+            },
+        );
+    }
+
     #[test]
     fn with_meta_implicit_from_entity() {
         assert_eq!(
@@ -328,25 +347,21 @@ mod tests {
     fn with_data_implicit_from_entity() {
         assert_eq!(
             RelationshipBuilder::default()
-                .data(Data::Single(Resource {
+                .data(IdentifierData::Single(ResourceIdentifier {
                     type_: "qwerties".into(),
                     id: Some("123".into()),
+                    lid: None,
                     meta: None,
-                    links: None,
-                    attributes: None,
-                    relationships: None,
                 }))
                 .unwrap(),
             Relationship {
                 meta: None,
                 links: None,
-                data: Some(Data::Single(Resource {
+                data: Some(IdentifierData::Single(ResourceIdentifier {
                     type_: "qwerties".into(),
                     id: Some("123".into()),
+                    lid: None,
                     meta: None,
-                    links: None,
-                    attributes: None,
-                    relationships: None,
                 })),
             },
         );
@@ -356,25 +371,42 @@ mod tests {
     fn with_data_single_implicit_from_entity() {
         assert_eq!(
             RelationshipBuilder::default()
-                .data(Resource {
+                .data(ResourceIdentifier {
                     type_: "qwerties".into(),
                     id: Some("123".into()),
+                    lid: None,
                     meta: None,
-                    links: None,
-                    attributes: None,
-                    relationships: None,
                 })
                 .unwrap(),
             Relationship {
                 meta: None,
                 links: None,
-                data: Some(Data::Single(Resource {
+                data: Some(IdentifierData::Single(ResourceIdentifier {
+                    type_: "qwerties".into(),
+                    id: Some("123".into()),
+                    lid: None,
+                    meta: None,
+                })),
+            },
+        );
+    }
+
+    #[test]
+    fn with_data_single_implicit_from_resource() {
+        let resource = ResourceBuilder::new_with_id("qwerties", "123")
+            .attr("foo", 123)
+            .unwrap();
+
+        assert_eq!(
+            RelationshipBuilder::default().data(&resource).unwrap(),
+            Relationship {
+                meta: None,
+                links: None,
+                data: Some(IdentifierData::Single(ResourceIdentifier {
                     type_: "qwerties".into(),
                     id: Some("123".into()),
+                    lid: None,
                     meta: None,
-                    links: None,
-                    attributes: None,
-                    relationships: None,
                 })),
             },
         );
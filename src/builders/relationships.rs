@@ -1,19 +1,28 @@
 use super::*;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct RelationshipsBuilder(HashMap<String, RelationshipBuilder>);
+pub struct RelationshipsBuilder(Map<String, RelationshipBuilder>);
 
 impl Builder<'_> for RelationshipsBuilder {
     type Entity = Relationships;
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
+        let mut errors = BuildErrors::default();
         let mut relationships = Relationships::new();
 
         for (name, relationship) in self.0 {
-            relationships.insert(name, relationship.finish()?);
+            if !member_name::is_valid(&name) {
+                errors.add_at(&name, "invalid member name");
+            }
+
+            if let Some(relationship) =
+                errors.nest(&name, relationship.finish())
+            {
+                relationships.insert(name, relationship);
+            }
         }
 
-        Ok(relationships)
+        errors.into_result(relationships)
     }
 }
 
@@ -31,7 +40,7 @@ impl RelationshipsBuilder {
 
 impl From<Relationships> for RelationshipsBuilder {
     fn from(relationships: Relationships) -> Self {
-        let mut new_relationships = HashMap::new();
+        let mut new_relationships = Map::new();
         for (key, value) in relationships {
             new_relationships.insert(key, value.into());
         }
@@ -56,7 +65,11 @@ mod tests {
     fn full() {
         assert_eq!(
             RelationshipsBuilder::default()
-                .rel("foo", RelationshipBuilder::default())
+                .rel(
+                    "foo",
+                    RelationshipBuilder::default()
+                        .data(IdentifierDataBuilder::Null),
+                )
                 .rel(
                     "bar",
                     RelationshipBuilder::default().meta(
@@ -67,9 +80,11 @@ mod tests {
                 )
                 .rel(
                     "car",
-                    RelationshipBuilder::default().data(DataBuilder::Single(
-                        ResourceBuilder::new("qwerties")
-                    )),
+                    RelationshipBuilder::default().data(
+                        IdentifierDataBuilder::Single(
+                            ResourceIdentifierBuilder::new("qwerties", "123"),
+                        ),
+                    ),
                 )
                 .unwrap(),
             {
@@ -79,7 +94,7 @@ mod tests {
                     Relationship {
                         meta: None,
                         links: None,
-                        data: None,
+                        data: Some(IdentifierData::Null),
                     },
                 );
                 relationships.insert(
@@ -95,14 +110,14 @@ mod tests {
                     Relationship {
                         meta: None,
                         links: None,
-                        data: Some(Data::Single(Resource {
-                            type_: "qwerties".into(),
-                            id: None,
-                            meta: None,
-                            links: None,
-                            attributes: None,
-                            relationships: None,
-                        })),
+                        data: Some(IdentifierData::Single(
+                            ResourceIdentifier {
+                                type_: "qwerties".into(),
+                                id: Some("123".into()),
+                                lid: None,
+                                meta: None,
+                            },
+                        )),
                     },
                 );
                 relationships
@@ -124,7 +139,7 @@ mod tests {
                 )
                 .unwrap(),
             {
-                let mut relationships = HashMap::new();
+                let mut relationships = Map::new();
                 relationships.insert(
                     "qwerty".into(),
                     Relationship {
@@ -139,4 +154,19 @@ mod tests {
     }
 
     // TODO: implicit tests
+
+    #[test]
+    fn invalid() {
+        let errors = RelationshipsBuilder::default()
+            .rel("foo", ResourceIdentifierBuilder::new("qwerties", 123))
+            .rel("-bar", ResourceIdentifierBuilder::new("qwerties", 456))
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "/-bar");
+        assert_eq!(error.reason(), "invalid member name");
+    }
 }
@@ -4,6 +4,7 @@ use super::*;
 pub struct ResourceBuilder {
     type_: String,
     id: Option<String>,
+    lid: Option<String>,
     meta: Option<MetaOrAttrsBuilder>,
     links: Option<LinksBuilder>,
     attributes: Option<MetaOrAttrsBuilder>,
@@ -15,6 +16,7 @@ impl ResourceBuilder {
         Self {
             type_: type_.to_string(),
             id: None,
+            lid: None,
             meta: None,
             links: None,
             attributes: None,
@@ -26,6 +28,7 @@ impl ResourceBuilder {
         Self {
             type_: type_.to_string(),
             id: Some(id.to_string()),
+            lid: None,
             meta: None,
             links: None,
             attributes: None,
@@ -38,25 +41,71 @@ impl Builder<'_> for ResourceBuilder {
     type Entity = Resource;
 
     fn finish(self) -> Result<Self::Entity, BuildErrors> {
-        Ok(Self::Entity {
+        let mut errors = BuildErrors::default();
+
+        if !member_name::is_valid(&self.type_) {
+            errors.add_at("type", "invalid type");
+        }
+
+        let meta = self
+            .meta
+            .and_then(|meta| errors.nest("meta", meta.finish()));
+        let links = self
+            .links
+            .and_then(|links| errors.nest("links", links.finish()));
+        let attributes = self.attributes.and_then(|attributes| {
+            errors.nest("attributes", attributes.finish())
+        });
+        let relationships = self.relationships.and_then(|relationships| {
+            errors.nest("relationships", relationships.finish())
+        });
+
+        // https://jsonapi.org/format/#document-resource-object-fields
+        if let Some(attributes) = &attributes {
+            for name in attributes.keys() {
+                if member_name::is_reserved_field(name) {
+                    errors.add(
+                        format!("/attributes/{}", json_pointer::escape(name)),
+                        "reserved field name",
+                    );
+                }
+            }
+        }
+
+        if let Some(relationships) = &relationships {
+            for name in relationships.keys() {
+                if member_name::is_reserved_field(name) {
+                    errors.add(
+                        format!(
+                            "/relationships/{}",
+                            json_pointer::escape(name)
+                        ),
+                        "reserved field name",
+                    );
+                }
+
+                if let Some(attributes) = &attributes {
+                    if attributes.contains_key(name) {
+                        errors.add(
+                            format!(
+                                "/relationships/{}",
+                                json_pointer::escape(name),
+                            ),
+                            "field is already an attribute",
+                        );
+                    }
+                }
+            }
+        }
+
+        errors.into_result(Self::Entity {
             type_: self.type_,
             id: self.id,
-            meta: match self.meta {
-                None => None,
-                Some(meta) => Some(meta.finish()?),
-            },
-            links: match self.links {
-                None => None,
-                Some(links) => Some(links.finish()?),
-            },
-            attributes: match self.attributes {
-                None => None,
-                Some(attributes) => Some(attributes.finish()?),
-            },
-            relationships: match self.relationships {
-                None => None,
-                Some(relationships) => Some(relationships.finish()?),
-            },
+            lid: self.lid,
+            meta,
+            links,
+            attributes,
+            relationships,
         })
     }
 }
@@ -69,6 +118,13 @@ impl ResourceBuilder {
         }
     }
 
+    pub fn lid<L: ToString>(self, lid: L) -> Self {
+        Self {
+            lid: Some(lid.to_string()),
+            ..self
+        }
+    }
+
     pub fn meta<M: Into<MetaOrAttrsBuilder>>(self, meta: M) -> Self {
         Self {
             meta: Some(meta.into()),
@@ -161,6 +217,7 @@ impl From<Resource> for ResourceBuilder {
         Self {
             type_: resource.type_,
             id: resource.id,
+            lid: resource.lid,
             meta: resource.meta.map(|meta| meta.into()),
             links: resource.links.map(|links| links.into()),
             attributes: resource.attributes.map(|attributes| attributes.into()),
@@ -183,6 +240,7 @@ mod tests {
             Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
@@ -198,6 +256,7 @@ mod tests {
             Resource {
                 type_: "qwerties".into(),
                 id: Some("123".into()),
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
@@ -211,6 +270,7 @@ mod tests {
         assert_eq!(
             ResourceBuilder::new("qwerties")
                 .id("123")
+                .lid("local-1")
                 .meta(
                     MetaOrAttrsBuilder::default()
                         .item("foo", 123)
@@ -233,16 +293,20 @@ mod tests {
                         .item("bar", "qwe"),
                 )
                 .relationships(
-                    RelationshipsBuilder::default()
-                        .rel("foo", RelationshipBuilder::default())
+                    RelationshipsBuilder::default().rel(
+                        "car",
+                        RelationshipBuilder::default()
+                            .data(IdentifierDataBuilder::Null),
+                    )
                 )
                 .unwrap(),
             Resource {
                 type_: "qwerties".into(),
                 id: Some("123".into()),
+                lid: Some("local-1".into()),
                 meta: Some(fixtures::meta_or_attrs()),
                 links: Some(Links {
-                    other: HashMap::new(),
+                    other: Map::new(),
                     self_: Some(Link::String("http://self.com".into())),
                     related: None,
                     first: None,
@@ -258,11 +322,11 @@ mod tests {
                 relationships: Some({
                     let mut relationships = Relationships::new();
                     relationships.insert(
-                        "foo".into(),
+                        "car".into(),
                         Relationship {
                             meta: None,
                             links: None,
-                            data: None,
+                            data: Some(IdentifierData::Null),
                         },
                     );
                     relationships
@@ -276,6 +340,7 @@ mod tests {
         assert_eq!(
             ResourceBuilder::new("qwerties")
                 .id("123")
+                .lid("local-1")
                 .meta1("foo", 123)
                 .meta1("bar", "qwe")
                 .link("self", LinkBuilder::new("http://self.com"))
@@ -289,14 +354,19 @@ mod tests {
                 )
                 .attr("foo", 123)
                 .attr("bar", "qwe")
-                .rel("foo", RelationshipBuilder::default())
+                .rel(
+                    "car",
+                    RelationshipBuilder::default()
+                        .data(IdentifierDataBuilder::Null),
+                )
                 .unwrap(),
             Resource {
                 type_: "qwerties".into(),
                 id: Some("123".into()),
+                lid: Some("local-1".into()),
                 meta: Some(fixtures::meta_or_attrs()),
                 links: Some(Links {
-                    other: HashMap::new(),
+                    other: Map::new(),
                     self_: Some(Link::String("http://self.com".into())),
                     related: None,
                     first: None,
@@ -312,11 +382,11 @@ mod tests {
                 relationships: Some({
                     let mut relationships = Relationships::new();
                     relationships.insert(
-                        "foo".into(),
+                        "car".into(),
                         Relationship {
                             meta: None,
                             links: None,
-                            data: None,
+                            data: Some(IdentifierData::Null),
                         },
                     );
                     relationships
@@ -335,6 +405,7 @@ mod tests {
             Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: Some({
                     let mut meta = MetaOrAttrs::new();
                     meta.insert("foo".into(), Value::Number(123.into()));
@@ -358,6 +429,7 @@ mod tests {
             Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: Some(fixtures::simple_links()),
                 attributes: None,
@@ -376,6 +448,7 @@ mod tests {
             Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: Some({
@@ -398,6 +471,7 @@ mod tests {
             Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: Some(fixtures::meta_or_attrs()),
                 links: None,
                 attributes: None,
@@ -415,6 +489,7 @@ mod tests {
             Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: Some(fixtures::simple_links()),
                 attributes: None,
@@ -432,6 +507,7 @@ mod tests {
             Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: Some(fixtures::meta_or_attrs()),
@@ -445,7 +521,7 @@ mod tests {
         assert_eq!(
             ResourceBuilder::new("qwerties")
                 .relationships({
-                    let mut relationships = HashMap::new();
+                    let mut relationships = Map::new();
                     relationships.insert(
                         "foo".into(),
                         Relationship {
@@ -460,11 +536,12 @@ mod tests {
             Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
                 relationships: Some({
-                    let mut relationships = HashMap::new();
+                    let mut relationships = Map::new();
                     relationships.insert(
                         "foo".into(),
                         Relationship {
@@ -495,11 +572,12 @@ mod tests {
             Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
                 relationships: Some({
-                    let mut relationships = HashMap::new();
+                    let mut relationships = Map::new();
                     relationships.insert(
                         "foo".into(),
                         Relationship {
@@ -515,4 +593,53 @@ mod tests {
     }
 
     // TODO: implicit tests
+
+    #[test]
+    fn invalid_type() {
+        let errors = ResourceBuilder::new("").unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "/type");
+        assert_eq!(error.reason(), "invalid type");
+    }
+
+    #[test]
+    fn reserved_fields() {
+        let errors = ResourceBuilder::new("qwerties")
+            .attr("type", "foobars")
+            .rel("links", ResourceIdentifierBuilder::new("qwerties", 123))
+            .unwrap_err();
+
+        let mut errors: Vec<(&str, &str)> = errors
+            .iter()
+            .map(|error| (error.pointer(), error.reason()))
+            .collect();
+        errors.sort_unstable();
+
+        assert_eq!(
+            errors,
+            vec![
+                ("/attributes/type", "reserved field name"),
+                ("/relationships/links", "reserved field name"),
+            ],
+        );
+    }
+
+    #[test]
+    fn common_fields_namespace() {
+        let errors = ResourceBuilder::new("qwerties")
+            .attr("foo", 123)
+            .rel("foo", ResourceIdentifierBuilder::new("qwerties", 123))
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "/relationships/foo");
+        assert_eq!(error.reason(), "field is already an attribute");
+    }
 }
@@ -0,0 +1,237 @@
+use super::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceIdentifierBuilder {
+    type_: String,
+    id: Option<String>,
+    lid: Option<String>,
+    meta: Option<MetaOrAttrsBuilder>,
+}
+
+impl ResourceIdentifierBuilder {
+    pub fn new<T: ToString, I: ToString>(type_: T, id: I) -> Self {
+        Self {
+            type_: type_.to_string(),
+            id: Some(id.to_string()),
+            lid: None,
+            meta: None,
+        }
+    }
+
+    pub fn new_with_lid<T: ToString, L: ToString>(type_: T, lid: L) -> Self {
+        Self {
+            type_: type_.to_string(),
+            id: None,
+            lid: Some(lid.to_string()),
+            meta: None,
+        }
+    }
+}
+
+impl Builder<'_> for ResourceIdentifierBuilder {
+    type Entity = ResourceIdentifier;
+
+    fn finish(self) -> Result<Self::Entity, BuildErrors> {
+        let mut errors = BuildErrors::default();
+
+        if !member_name::is_valid(&self.type_) {
+            errors.add_at("type", "invalid type");
+        }
+
+        if self.id.is_none() && self.lid.is_none() {
+            errors.add("", "resource identifier must contain id or lid");
+        }
+
+        let meta = self
+            .meta
+            .and_then(|meta| errors.nest("meta", meta.finish()));
+
+        errors.into_result(Self::Entity {
+            type_: self.type_,
+            id: self.id,
+            lid: self.lid,
+            meta,
+        })
+    }
+}
+
+impl ResourceIdentifierBuilder {
+    pub fn id<I: ToString>(self, id: I) -> Self {
+        Self {
+            id: Some(id.to_string()),
+            ..self
+        }
+    }
+
+    pub fn lid<L: ToString>(self, lid: L) -> Self {
+        Self {
+            lid: Some(lid.to_string()),
+            ..self
+        }
+    }
+
+    pub fn meta<M: Into<MetaOrAttrsBuilder>>(self, meta: M) -> Self {
+        Self {
+            meta: Some(meta.into()),
+            ..self
+        }
+    }
+
+    pub fn meta1<N: ToString, V: Into<Value>>(self, name: N, meta1: V) -> Self {
+        let meta = self.meta.unwrap_or_default().item(name, meta1);
+
+        Self {
+            meta: Some(meta),
+            ..self
+        }
+    }
+}
+
+impl From<ResourceIdentifier> for ResourceIdentifierBuilder {
+    fn from(resource_identifier: ResourceIdentifier) -> Self {
+        Self {
+            type_: resource_identifier.type_,
+            id: resource_identifier.id,
+            lid: resource_identifier.lid,
+            meta: resource_identifier.meta.map(|meta| meta.into()),
+        }
+    }
+}
+
+impl From<&Resource> for ResourceIdentifierBuilder {
+    fn from(resource: &Resource) -> Self {
+        ResourceIdentifier::from(resource).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            ResourceIdentifierBuilder::new("qwerties", 123).unwrap(),
+            ResourceIdentifier {
+                type_: "qwerties".into(),
+                id: Some("123".into()),
+                lid: None,
+                meta: None,
+            },
+        );
+    }
+
+    #[test]
+    fn empty_with_lid() {
+        assert_eq!(
+            ResourceIdentifierBuilder::new_with_lid("qwerties", "local-1")
+                .unwrap(),
+            ResourceIdentifier {
+                type_: "qwerties".into(),
+                id: None,
+                lid: Some("local-1".into()),
+                meta: None,
+            },
+        );
+    }
+
+    #[test]
+    fn full() {
+        assert_eq!(
+            ResourceIdentifierBuilder::new("qwerties", 123)
+                .id(456)
+                .lid("local-1")
+                .meta(
+                    MetaOrAttrsBuilder::default()
+                        .item("foo", 123)
+                        .item("bar", "qwe"),
+                )
+                .unwrap(),
+            ResourceIdentifier {
+                type_: "qwerties".into(),
+                id: Some("456".into()),
+                lid: Some("local-1".into()),
+                meta: Some(fixtures::meta_or_attrs()),
+            },
+        );
+    }
+
+    #[test]
+    fn full_delegators() {
+        assert_eq!(
+            ResourceIdentifierBuilder::new("qwerties", 123)
+                .meta1("foo", 123)
+                .meta1("bar", "qwe")
+                .unwrap(),
+            ResourceIdentifier {
+                type_: "qwerties".into(),
+                id: Some("123".into()),
+                lid: None,
+                meta: Some(fixtures::meta_or_attrs()),
+            },
+        );
+    }
+
+    #[test]
+    fn implicit_from_entity() {
+        let resource_identifier = ResourceIdentifier {
+            type_: "qwerties".into(),
+            id: Some("123".into()),
+            lid: Some("local-1".into()),
+            meta: Some(fixtures::meta_or_attrs()),
+        };
+
+        let builder: ResourceIdentifierBuilder =
+            resource_identifier.clone().into();
+
+        assert_eq!(builder.unwrap(), resource_identifier);
+    }
+
+    #[test]
+    fn implicit_from_resource() {
+        let resource = ResourceBuilder::new_with_id("qwerties", 123)
+            .attr("foo", 123)
+            .unwrap();
+
+        let builder: ResourceIdentifierBuilder = (&resource).into();
+
+        assert_eq!(builder, ResourceIdentifierBuilder::new("qwerties", 123));
+    }
+
+    #[test]
+    fn without_id_and_lid() {
+        let builder: ResourceIdentifierBuilder = ResourceIdentifier {
+            type_: "qwerties".into(),
+            id: None,
+            lid: None,
+            meta: None,
+        }
+        .into();
+
+        let errors = builder.unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "");
+        assert_eq!(
+            error.reason(),
+            "resource identifier must contain id or lid"
+        );
+    }
+
+    #[test]
+    fn invalid_type() {
+        let errors =
+            ResourceIdentifierBuilder::new("qwerties!", 123).unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+
+        let error = errors.iter().next().unwrap();
+
+        assert_eq!(error.pointer(), "/type");
+        assert_eq!(error.reason(), "invalid type");
+    }
+}
@@ -0,0 +1,197 @@
+use super::*;
+
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::header::AUTHORIZATION;
+
+// Credentials which are sent with every request of a client.
+#[derive(Clone)]
+pub enum Auth {
+    Bearer(String),
+    Basic {
+        username: String,
+        password: Option<String>,
+    },
+    Headers(HeaderMap),
+    // The provider is asked for headers before every request. When the
+    // server responds with 401 Unauthorized, it's asked again with `refresh`
+    // set and the request is retried once.
+    Provider(Arc<dyn AuthProvider>),
+}
+
+pub trait AuthProvider: Send + Sync {
+    fn headers(&self, refresh: bool) -> HeaderMap;
+}
+
+impl<F> AuthProvider for F
+where
+    F: Fn(bool) -> HeaderMap + Send + Sync,
+{
+    fn headers(&self, refresh: bool) -> HeaderMap {
+        self(refresh)
+    }
+}
+
+impl Auth {
+    pub fn bearer<T: ToString>(token: T) -> Self {
+        Self::Bearer(token.to_string())
+    }
+
+    pub fn basic<U: ToString, P: ToString>(username: U, password: P) -> Self {
+        Self::Basic {
+            username: username.to_string(),
+            password: Some(password.to_string()),
+        }
+    }
+
+    pub fn provider<P: AuthProvider + 'static>(provider: P) -> Self {
+        Self::Provider(Arc::new(provider))
+    }
+
+    pub(super) fn can_refresh(&self) -> bool {
+        matches!(self, Self::Provider(_))
+    }
+
+    pub(super) fn headers(
+        &self,
+        refresh: bool,
+    ) -> std::result::Result<HeaderMap, InvalidHeaderValue> {
+        let authorization = |value: String| {
+            let mut value = HeaderValue::from_str(&value)?;
+            value.set_sensitive(true);
+
+            let mut headers = HeaderMap::new();
+            headers.insert(AUTHORIZATION, value);
+
+            Ok(headers)
+        };
+
+        match self {
+            Self::Bearer(token) => authorization(format!("Bearer {}", token)),
+            Self::Basic { username, password } => {
+                let credentials = format!(
+                    "{}:{}",
+                    username,
+                    password.as_deref().unwrap_or_default(),
+                );
+
+                authorization(format!("Basic {}", STANDARD.encode(credentials)))
+            }
+            Self::Headers(headers) => {
+                let mut headers = headers.clone();
+
+                for value in headers.values_mut() {
+                    value.set_sensitive(true);
+                }
+
+                Ok(headers)
+            }
+            Self::Provider(provider) => Ok(provider.headers(refresh)),
+        }
+    }
+}
+
+// Secrets are not printed.
+impl Debug for Auth {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bearer(_) => formatter.write_str("Bearer(..)"),
+            Self::Basic { username, .. } => formatter
+                .debug_struct("Basic")
+                .field("username", username)
+                .finish_non_exhaustive(),
+            Self::Headers(headers) => formatter
+                .debug_tuple("Headers")
+                .field(&headers.keys().collect::<Vec<_>>())
+                .finish(),
+            Self::Provider(_) => formatter.write_str("Provider(..)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authorization(auth: &Auth) -> HeaderValue {
+        auth.headers(false).unwrap().remove(AUTHORIZATION).unwrap()
+    }
+
+    #[test]
+    fn bearer() {
+        let value = authorization(&Auth::bearer("token"));
+
+        assert_eq!(value, "Bearer token");
+        assert!(value.is_sensitive());
+    }
+
+    #[test]
+    fn basic() {
+        let value = authorization(&Auth::basic("user", "pass"));
+
+        assert_eq!(value, "Basic dXNlcjpwYXNz");
+        assert!(value.is_sensitive());
+
+        let auth = Auth::Basic {
+            username: "user".into(),
+            password: None,
+        };
+
+        assert_eq!(authorization(&auth), "Basic dXNlcjo=");
+    }
+
+    #[test]
+    fn headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("secret"));
+
+        let sent = Auth::Headers(headers.clone()).headers(false).unwrap();
+
+        assert_eq!(sent, headers);
+        assert!(sent["x-api-key"].is_sensitive());
+    }
+
+    #[test]
+    fn provider() {
+        let auth = Auth::provider(|refresh: bool| {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_static(if refresh { "new" } else { "old" }),
+            );
+            headers
+        });
+
+        assert!(auth.can_refresh());
+        assert_eq!(authorization(&auth), "old");
+        assert_eq!(auth.headers(true).unwrap()[AUTHORIZATION], "new");
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Auth::bearer("line\nbreak").headers(false).is_err());
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Auth::bearer("token")), "Bearer(..)");
+        assert_eq!(
+            format!("{:?}", Auth::basic("user", "pass")),
+            "Basic { username: \"user\", .. }",
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("topsecret"));
+
+        assert_eq!(
+            format!("{:?}", Auth::Headers(headers)),
+            "Headers([\"x-api-key\"])",
+        );
+        assert_eq!(
+            format!("{:?}", Auth::provider(|_| HeaderMap::new())),
+            "Provider(..)",
+        );
+    }
+}
@@ -0,0 +1,1704 @@
+use super::*;
+
+use std::time::Instant;
+
+use reqwest::blocking::{Client as ReqClient, Response as ReqResponse};
+
+#[derive(Clone, Debug)]
+pub struct Client {
+    base: Base,
+    http: ReqClient,
+    // Whether the HTTP client was given by the caller rather than built here.
+    custom_http: bool,
+}
+
+// Yields the given response and then the pages which are fetched by
+// following "next" links, until there are none, a link leads to a page which
+// was already fetched or the limit is reached.
+#[derive(Debug)]
+pub struct Pages<'c> {
+    client: &'c Client,
+    pager: Pager,
+}
+
+impl Client {
+    pub fn new<U: Into<String>>(url: U) -> Self {
+        Self {
+            custom_http: false,
+            ..Self::with_http_client(url, ReqClient::new())
+        }
+    }
+
+    // The HTTP client is reused for all requests, so it should be configured
+    // (proxies, TLS, default headers, connect timeout and so on) beforehand.
+    pub fn with_http_client<U: Into<String>>(url: U, http: ReqClient) -> Self {
+        Self {
+            base: Base::new(url.into()),
+            http,
+            custom_http: true,
+        }
+    }
+
+    pub fn add_json_ext(self, add_json_ext: bool) -> Self {
+        Self {
+            base: Base {
+                add_json_ext,
+                ..self.base
+            },
+            ..self
+        }
+    }
+
+    // Rejects responses which violate MUST requirements of the specification.
+    pub fn strict(self, strict: bool) -> Self {
+        Self {
+            base: Base {
+                strict,
+                ..self.base
+            },
+            ..self
+        }
+    }
+
+    pub fn auth(self, auth: Auth) -> Self {
+        Self {
+            base: Base {
+                auth: Some(auth),
+                ..self.base
+            },
+            ..self
+        }
+    }
+
+    // Middleware is applied in the order in which it is added.
+    pub fn middleware<M: Middleware + 'static>(self, middleware: M) -> Self {
+        let mut base = self.base;
+        base.middleware.push(Arc::new(middleware));
+
+        Self { base, ..self }
+    }
+
+    pub fn retry(self, retry: RetryPolicy) -> Self {
+        Self {
+            base: Base {
+                retry: Some(retry),
+                ..self.base
+            },
+            ..self
+        }
+    }
+
+    // Default timeouts of requests, the ones which are set replace the
+    // current ones. The connect timeout is a setting of the HTTP client, so
+    // it's only supported with the default one, which is rebuilt when it
+    // changes. Set it on the HTTP client given to `with_http_client` instead.
+    pub fn timeouts(
+        self,
+        timeouts: Timeouts,
+    ) -> std::result::Result<Self, Error> {
+        let timeouts = self.base.timeouts.merge(timeouts);
+
+        let http = match timeouts.connect {
+            Some(_) if self.custom_http => {
+                return Err(Error::UnsupportedConnectTimeout)
+            }
+            Some(connect) if timeouts.connect != self.base.timeouts.connect => {
+                ReqClient::builder()
+                    .connect_timeout(connect)
+                    .build()
+                    .map_err(Error::Http)?
+            }
+            _ => self.http,
+        };
+
+        Ok(Self {
+            base: Base {
+                timeouts,
+                ..self.base
+            },
+            http,
+            ..self
+        })
+    }
+
+    // Client for some requests with other read and total timeouts, which
+    // shares the HTTP client and so its connection pool with this one.
+    pub fn with_timeouts(
+        &self,
+        timeouts: Timeouts,
+    ) -> std::result::Result<Self, Error> {
+        if timeouts.connect.is_some() {
+            return Err(Error::UnsupportedConnectTimeout);
+        }
+
+        Ok(Self {
+            base: Base {
+                timeouts: self.base.timeouts.merge(timeouts),
+                ..self.base.clone()
+            },
+            ..self.clone()
+        })
+    }
+
+    pub fn get<P, I, K, V>(&self, path: P, params: I) -> Result
+    where
+        P: Display,
+        I: IntoIterator,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        <I as IntoIterator>::Item: std::borrow::Borrow<(K, V)>,
+    {
+        let url = self.base.url_for_get(path, params)?;
+
+        self.make_request(Method::GET, url, None, Outcome::fetching)
+    }
+
+    pub fn post<'d, P, D>(&self, path: P, document: D) -> Result
+    where
+        P: Display,
+        D: Into<&'d Document>,
+    {
+        let url = self.base.url_for(path)?;
+        let body = Base::document_body(document.into())?;
+
+        self.make_request(Method::POST, url, Some(body), Outcome::creating)
+    }
+
+    pub fn patch<'d, P, D>(&self, path: P, document: D) -> Result
+    where
+        P: Display,
+        D: Into<&'d Document>,
+    {
+        let url = self.base.url_for(path)?;
+        let body = Base::document_body(document.into())?;
+
+        self.make_request(Method::PATCH, url, Some(body), Outcome::updating)
+    }
+
+    pub fn delete<P>(&self, path: P) -> Result
+    where
+        P: Display,
+    {
+        let url = self.base.url_for(path)?;
+
+        self.make_request(Method::DELETE, url, None, Outcome::deleting)
+    }
+
+    // https://jsonapi.org/format/#fetching-relationships
+    pub fn get_relationship<P, N>(&self, path: P, name: N) -> Result
+    where
+        P: Display,
+        N: Display,
+    {
+        let url = self.base.url_for_relationship(path, name)?;
+
+        self.make_request(Method::GET, url, None, Outcome::fetching)
+    }
+
+    // https://jsonapi.org/format/#crud-updating-relationships
+    pub fn update_relationship<'d, P, N, D>(
+        &self,
+        path: P,
+        name: N,
+        data: D,
+    ) -> Result
+    where
+        P: Display,
+        N: Display,
+        D: Into<&'d IdentifierData>,
+    {
+        self.send_linkage(Method::PATCH, path, name, data.into())
+    }
+
+    pub fn add_to_relationship<P, N>(
+        &self,
+        path: P,
+        name: N,
+        identifiers: &[ResourceIdentifier],
+    ) -> Result
+    where
+        P: Display,
+        N: Display,
+    {
+        self.send_linkage(Method::POST, path, name, identifiers)
+    }
+
+    pub fn remove_from_relationship<P, N>(
+        &self,
+        path: P,
+        name: N,
+        identifiers: &[ResourceIdentifier],
+    ) -> Result
+    where
+        P: Display,
+        N: Display,
+    {
+        self.send_linkage(Method::DELETE, path, name, identifiers)
+    }
+
+    fn send_linkage<P, N, D>(
+        &self,
+        method: Method,
+        path: P,
+        name: N,
+        data: D,
+    ) -> Result
+    where
+        P: Display,
+        N: Display,
+        D: Serialize,
+    {
+        let url = self.base.url_for_relationship(path, name)?;
+        let body = Base::linkage_body(data)?;
+
+        // https://jsonapi.org/format/#crud-updating-relationship-responses
+        self.make_request(method, url, Some(body), Outcome::updating)
+    }
+
+    pub fn pages(&self, response: Response) -> Pages<'_> {
+        Pages {
+            client: self,
+            pager: Pager::new(response),
+        }
+    }
+
+    // Fetches a link of a document, e.g. "self" or "related" link.
+    pub fn follow(&self, link: &Link) -> Result {
+        let url = self.base.url_for_link(link)?;
+
+        self.make_request(Method::GET, url, None, Outcome::fetching)
+    }
+
+    // https://jsonapi.org/format/#document-resource-object-related-resource-links
+    pub fn fetch_related(&self, resource: &Resource, name: &str) -> Result {
+        self.follow(Base::related_link(resource, name)?)
+    }
+
+    fn make_request(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<Vec<u8>>,
+        outcome: fn(StatusCode) -> Option<Outcome>,
+    ) -> Result {
+        let start = Instant::now();
+        let mut attempt = 1;
+
+        let response = loop {
+            let response = self.send_authorized(&method, &url, &body, start)?;
+
+            match self.base.retry_delay(
+                &method,
+                response.status(),
+                response.headers(),
+                attempt,
+            ) {
+                None => break response,
+                Some(delay) => {
+                    self.base.timeouts.check_total(start, delay)?;
+                    std::thread::sleep(delay);
+                }
+            }
+
+            attempt += 1;
+        };
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let body = if Base::has_body(status) {
+            Some(response.bytes().map_err(Error::from_text)?.to_vec())
+        } else {
+            None
+        };
+
+        self.base.timeouts.check_total(start, Duration::ZERO)?;
+
+        self.base.finish(
+            url,
+            RawResponse {
+                status,
+                headers,
+                body,
+            },
+            outcome,
+        )
+    }
+
+    // Credentials are refreshed and the request is retried once when it is
+    // rejected with 401 Unauthorized.
+    fn send_authorized(
+        &self,
+        method: &Method,
+        url: &Url,
+        body: &Option<Vec<u8>>,
+        start: Instant,
+    ) -> std::result::Result<ReqResponse, Error> {
+        let request = self.base.request(
+            method.clone(),
+            url.clone(),
+            body.clone(),
+            false,
+        )?;
+        let response = self.send(request, start)?;
+
+        if response.status() == StatusCode::UNAUTHORIZED
+            && self.base.can_refresh_auth()
+        {
+            let request = self.base.request(
+                method.clone(),
+                url.clone(),
+                body.clone(),
+                true,
+            )?;
+
+            return self.send(request, start);
+        }
+
+        Ok(response)
+    }
+
+    fn send(
+        &self,
+        request: Request,
+        start: Instant,
+    ) -> std::result::Result<ReqResponse, Error> {
+        let mut request_builder = self
+            .http
+            .request(request.method, request.url)
+            .headers(request.headers);
+
+        if let Some(body) = request.body {
+            request_builder = request_builder.body(body);
+        }
+
+        // The blocking HTTP client applies the timeout separately to waiting
+        // for the response and to reading its body, so the total timeout is
+        // also checked when the body is read.
+        if let Some(timeout) = self.base.timeouts.shortest(start) {
+            request_builder = request_builder.timeout(timeout);
+        }
+
+        request_builder.send().map_err(Error::from_http)
+    }
+}
+
+impl<'c> Pages<'c> {
+    // Maximum number of pages, including the initial one.
+    pub fn limit(self, limit: usize) -> Self {
+        Self {
+            pager: Pager {
+                limit: Some(limit),
+                ..self.pager
+            },
+            ..self
+        }
+    }
+
+    // Resources of the primary data across all pages.
+    pub fn resources(
+        self,
+    ) -> impl Iterator<Item = std::result::Result<Resource, Error>> + 'c {
+        self.flat_map(Pager::resources)
+    }
+}
+
+impl Iterator for Pages<'_> {
+    type Item = Result;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = match self.pager.next()? {
+            Page::Ready(response) => Ok(*response),
+            Page::Fetch(link) => self.client.follow(&link),
+        };
+
+        self.pager.fetched(&self.client.base, &result);
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::test_server::{Reply, TestServer, UnresponsiveServer, MIME};
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use serde_json::{json, Value};
+
+    fn conflict_value() -> Value {
+        json!({
+            "errors": [{ "status": "409", "title": "Conflict" }],
+        })
+    }
+
+    #[test]
+    fn get() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            fixtures::article_value(),
+        )]);
+
+        let response = Client::new(server.url())
+            .get("/articles/1", [("include", "author")])
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Fetched);
+        assert_eq!(response.document().unwrap(), &fixtures::article());
+
+        let request = server.request();
+
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/articles/1?include=author");
+        assert_eq!(request.header("accept"), Some(MIME));
+    }
+
+    #[test]
+    fn get_with_query() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            fixtures::article_value(),
+        )]);
+
+        let query = Query::new()
+            .include("author")
+            .fields("articles", ["title", "body"])
+            .sort_desc("created")
+            .page("number", 2)
+            .filter("tag", "rust lang");
+
+        Client::new(server.url()).get("/articles", &query).unwrap();
+
+        assert_eq!(server.request().path, format!("/articles?{}", query),);
+    }
+
+    #[test]
+    fn get_invalid_status() {
+        let server = TestServer::start(vec![Reply::document(
+            201,
+            fixtures::article_value(),
+        )]);
+
+        assert!(matches!(
+            Client::new(server.url()).get("/articles/1", [("", ""); 0]),
+            Err(Error::InvalidStatus(StatusCode::CREATED)),
+        ));
+    }
+
+    #[test]
+    fn post() {
+        for (status, outcome) in [
+            (201, Outcome::Created),
+            (200, Outcome::Created),
+            (202, Outcome::Accepted),
+        ] {
+            let server = TestServer::start(vec![Reply::document(
+                status,
+                fixtures::article_value(),
+            )
+            .header("Location", "http://example.com/articles/1")]);
+
+            let response = Client::new(server.url())
+                .post("/articles", &fixtures::article())
+                .unwrap();
+
+            assert_eq!(response.outcome(), outcome);
+            assert_eq!(
+                response.location(),
+                Some("http://example.com/articles/1"),
+            );
+
+            let request = server.request();
+
+            assert_eq!(request.method, "POST");
+            assert_eq!(request.path, "/articles");
+            assert_eq!(request.header("content-type"), Some(MIME));
+            assert_eq!(request.json(), fixtures::article_value());
+        }
+    }
+
+    #[test]
+    fn post_server_error() {
+        let server = TestServer::start(vec![Reply::document(
+            500,
+            json!({ "errors": [{ "status": "500" }] }),
+        )]);
+
+        match Client::new(server.url()).post("/articles", &fixtures::article())
+        {
+            Err(Error::Response(response)) => {
+                assert_eq!(response.outcome(), Outcome::Failed)
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn patch() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            fixtures::article_value(),
+        )]);
+
+        let response = Client::new(server.url())
+            .patch("/articles/1", &fixtures::article())
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Updated);
+        assert_eq!(response.document().unwrap(), &fixtures::article());
+
+        let request = server.request();
+
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(request.path, "/articles/1");
+        assert_eq!(request.header("content-type"), Some(MIME));
+        assert_eq!(request.header("accept"), Some(MIME));
+        assert_eq!(request.json(), fixtures::article_value());
+    }
+
+    #[test]
+    fn strict() {
+        let server = TestServer::start(vec![
+            Reply::document(
+                200,
+                json!({
+                    "data": [
+                        { "type": "articles", "id": "1" },
+                        { "type": "articles", "id": "1" },
+                    ],
+                }),
+            ),
+            Reply::document(200, json!({ "meta": { "foo_bar": 1 } })),
+        ]);
+        let client = Client::new(server.url()).strict(true);
+
+        match client.get("/articles", [("", ""); 0]) {
+            Err(Error::Validation(report)) => assert!(!report.is_valid()),
+            other => panic!("{:?}", other),
+        }
+
+        let response = client.get("/articles", [("", ""); 0]).unwrap();
+
+        assert_eq!(
+            response.document().unwrap().meta.as_ref().unwrap()["foo_bar"],
+            1,
+        );
+    }
+
+    #[test]
+    fn without_document() {
+        let server = TestServer::start(vec![
+            Reply::new(202),
+            Reply::new(202),
+            Reply::new(201).header("Location", "/articles/1"),
+        ]);
+        let client = Client::new(server.url());
+
+        let response = client.delete("/articles/1").unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Accepted);
+        assert_eq!(response.document(), None);
+
+        let response =
+            client.patch("/articles/1", &fixtures::article()).unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Accepted);
+        assert_eq!(response.document(), None);
+
+        let response = client.post("/articles", &fixtures::article()).unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Created);
+        assert_eq!(response.document(), None);
+        assert_eq!(response.location(), Some("/articles/1"));
+    }
+
+    #[test]
+    fn patch_accepted() {
+        let server = TestServer::start(vec![Reply::document(
+            202,
+            json!({ "meta": { "status": "pending" } }),
+        )]);
+
+        let response = Client::new(server.url())
+            .patch("/articles/1", &fixtures::article())
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Accepted);
+        assert_eq!(
+            response.document().unwrap().meta.as_ref().unwrap()["status"],
+            "pending"
+        );
+    }
+
+    #[test]
+    fn patch_conflict() {
+        let server =
+            TestServer::start(vec![Reply::document(409, conflict_value())]);
+
+        match Client::new(server.url())
+            .patch("/articles/1", &fixtures::article())
+        {
+            Err(Error::Response(response)) => {
+                assert_eq!(response.outcome(), Outcome::Failed);
+                assert_eq!(
+                    response.document().unwrap().errors.as_ref().unwrap()[0]
+                        .title,
+                    Some("Conflict".into()),
+                );
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn patch_invalid_status() {
+        let server = TestServer::start(vec![Reply::document(
+            201,
+            fixtures::article_value(),
+        )]);
+
+        assert!(matches!(
+            Client::new(server.url())
+                .patch("/articles/1", &fixtures::article()),
+            Err(Error::InvalidStatus(StatusCode::CREATED)),
+        ));
+    }
+
+    #[test]
+    fn delete() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            json!({ "meta": { "deleted": true } }),
+        )]);
+
+        let response = Client::new(server.url()).delete("/articles/1").unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Deleted);
+        assert_eq!(
+            response.document().unwrap().meta.as_ref().unwrap()["deleted"],
+            true
+        );
+
+        let request = server.request();
+
+        assert_eq!(request.method, "DELETE");
+        assert_eq!(request.path, "/articles/1");
+        assert_eq!(request.body, "");
+    }
+
+    #[test]
+    fn delete_not_found() {
+        let server = TestServer::start(vec![Reply::document(
+            404,
+            json!({ "errors": [{ "status": "404" }] }),
+        )]);
+
+        match Client::new(server.url()).delete("/articles/1") {
+            Err(Error::Response(response)) => assert_eq!(
+                response.document().unwrap().errors.as_ref().unwrap()[0].status,
+                Some("404".parse().unwrap()),
+            ),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn error_without_document() {
+        let server = TestServer::start(vec![
+            Reply::new(404),
+            Reply::new(401),
+            Reply::new(503)
+                .header("Content-Type", "text/html")
+                .body("<h1>Service Unavailable</h1>"),
+        ]);
+        let client = Client::new(server.url());
+
+        for status in [
+            StatusCode::NOT_FOUND,
+            StatusCode::UNAUTHORIZED,
+            StatusCode::SERVICE_UNAVAILABLE,
+        ] {
+            match client.get("/articles/1", [("", ""); 0]) {
+                Err(Error::Response(response)) => {
+                    assert_eq!(response.status(), status);
+                    assert_eq!(response.outcome(), Outcome::Failed);
+                    assert_eq!(response.document(), None);
+                }
+                other => panic!("{:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn error_with_invalid_document() {
+        let server = TestServer::start(vec![Reply::new(500)
+            .header("Content-Type", MIME)
+            .body("{")]);
+
+        match Client::new(server.url()).get("/articles/1", [("", ""); 0]) {
+            Err(Error::Response(response)) => {
+                assert_eq!(
+                    response.status(),
+                    StatusCode::INTERNAL_SERVER_ERROR
+                );
+                assert_eq!(response.document(), None);
+                assert_eq!(response.body(), Some(&b"{"[..]));
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn invalid_status_without_document() {
+        let server = TestServer::start(vec![Reply::new(201)]);
+
+        assert!(matches!(
+            Client::new(server.url()).get("/articles/1", [("", ""); 0]),
+            Err(Error::InvalidStatus(StatusCode::CREATED)),
+        ));
+    }
+
+    #[test]
+    fn charset() {
+        let server = TestServer::start(vec![Reply::new(200)
+            .header("Content-Type", format!("{}; charset=iso-8859-1", MIME))
+            .bytes(b"{\"meta\":{\"title\":\"Caf\xe9\"}}".to_vec())]);
+        let client = Client::new(server.url());
+
+        let response = client.get("/articles/1", [("", ""); 0]).unwrap();
+
+        assert_eq!(
+            response.document().unwrap().meta.as_ref().unwrap()["title"],
+            "Caf\u{e9}",
+        );
+        assert_eq!(
+            response.body(),
+            Some(&b"{\"meta\":{\"title\":\"Caf\xe9\"}}"[..]),
+        );
+        assert_eq!(
+            response.text().as_deref(),
+            Some("{\"meta\":{\"title\":\"Caf\u{e9}\"}}"),
+        );
+    }
+
+    #[test]
+    fn raw_body() {
+        let server = TestServer::start(vec![Reply::new(502)
+            .header("Content-Type", "text/plain; charset=utf-8")
+            .bytes(b"Bad \xff Gateway".to_vec())]);
+
+        match Client::new(server.url()).get("/articles/1", [("", ""); 0]) {
+            Err(Error::Response(response)) => {
+                assert_eq!(response.body(), Some(&b"Bad \xff Gateway"[..]));
+                assert_eq!(
+                    response.text().as_deref(),
+                    Some("Bad \u{fffd} Gateway"),
+                );
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn no_content() {
+        let server = TestServer::start(vec![
+            Reply::new(204),
+            Reply::new(204),
+            Reply::new(204),
+        ]);
+        let client = Client::new(server.url());
+
+        let response = client.post("/articles", &fixtures::article()).unwrap();
+
+        assert_eq!(response.outcome(), Outcome::CreatedWithoutContent);
+        assert_eq!(response.document(), None);
+
+        let response =
+            client.patch("/articles/1", &fixtures::article()).unwrap();
+
+        assert_eq!(response.outcome(), Outcome::UpdatedWithoutContent);
+        assert_eq!(response.document(), None);
+
+        let response = client.delete("/articles/1").unwrap();
+
+        assert_eq!(response.outcome(), Outcome::DeletedWithoutContent);
+        assert_eq!(response.document(), None);
+    }
+
+    #[test]
+    fn not_modified() {
+        let server =
+            TestServer::start(vec![Reply::new(304).header("ETag", "\"1\"")]);
+
+        let response = Client::new(server.url())
+            .get("/articles/1", [("", ""); 0])
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::NotModified);
+        assert_eq!(response.document(), None);
+    }
+
+    #[test]
+    fn no_content_type() {
+        let server = TestServer::start(vec![
+            Reply::new(200).body(fixtures::article_value()),
+            Reply::new(200)
+                .header("Content-Type", "application/json")
+                .body(fixtures::article_value()),
+        ]);
+        let client = Client::new(server.url());
+
+        assert!(matches!(
+            client.get("/articles/1", [("", ""); 0]),
+            Err(Error::NoContentType),
+        ));
+        assert!(matches!(
+            client.get("/articles/1", [("", ""); 0]),
+            Err(Error::InvalidContentType(_)),
+        ));
+    }
+
+    #[test]
+    fn get_relationship() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            json!({
+                "links": {
+                    "self": "/articles/1/relationships/author",
+                    "related": "/articles/1/author",
+                },
+                "data": {
+                    "type": "people",
+                    "id": "12",
+                    "meta": { "role": "editor" },
+                },
+            }),
+        )]);
+
+        let response = Client::new(server.url())
+            .get_relationship("/articles/1", "author")
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Fetched);
+        assert_eq!(
+            response.linkage(),
+            Some(IdentifierData::Single(
+                ResourceIdentifierBuilder::new("people", "12")
+                    .meta1("role", "editor")
+                    .unwrap(),
+            )),
+        );
+
+        let request = server.request();
+
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/articles/1/relationships/author");
+    }
+
+    #[test]
+    fn update_relationship() {
+        let server = TestServer::start(vec![
+            Reply::new(204),
+            Reply::document(200, json!({ "data": null })),
+        ]);
+        let client = Client::new(server.url());
+
+        let response = client
+            .update_relationship(
+                "/articles/1",
+                "tags",
+                &IdentifierData::Multiple(fixtures::tags()),
+            )
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::UpdatedWithoutContent);
+        assert_eq!(response.linkage(), None);
+
+        let request = server.request();
+
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(request.path, "/articles/1/relationships/tags");
+        assert_eq!(request.header("content-type"), Some(MIME));
+        assert_eq!(request.json(), fixtures::tags_value());
+
+        let response = client
+            .update_relationship("/articles/1", "author", &IdentifierData::Null)
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Updated);
+        assert_eq!(response.linkage(), Some(IdentifierData::Null));
+        assert_eq!(server.request().json(), json!({ "data": null }));
+    }
+
+    #[test]
+    fn add_to_relationship() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            fixtures::tags_value(),
+        )]);
+
+        let response = Client::new(server.url())
+            .add_to_relationship("/articles/1", "tags", &fixtures::tags())
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Updated);
+        assert_eq!(
+            response.linkage(),
+            Some(IdentifierData::Multiple(fixtures::tags()))
+        );
+
+        let request = server.request();
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/articles/1/relationships/tags");
+        assert_eq!(request.json(), fixtures::tags_value());
+    }
+
+    #[test]
+    fn remove_from_relationship() {
+        let server = TestServer::start(vec![Reply::document(
+            403,
+            json!({ "errors": [{ "status": "403" }] }),
+        )]);
+
+        assert!(matches!(
+            Client::new(server.url()).remove_from_relationship(
+                "/articles/1",
+                "tags",
+                &fixtures::tags()[..1],
+            ),
+            Err(Error::Response(_)),
+        ));
+
+        let request = server.request();
+
+        assert_eq!(request.method, "DELETE");
+        assert_eq!(request.path, "/articles/1/relationships/tags");
+        assert_eq!(
+            request.json(),
+            json!({ "data": [{ "type": "tags", "id": "2" }] }),
+        );
+    }
+
+    #[test]
+    fn with_http_client() {
+        let server = TestServer::start(vec![
+            Reply::new(204),
+            Reply::document(200, fixtures::article_value()),
+        ]);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("secret"));
+
+        let client = Client::with_http_client(
+            server.url(),
+            ReqClient::builder()
+                .default_headers(headers)
+                .build()
+                .unwrap(),
+        );
+
+        client.delete("/articles/1").unwrap();
+        client.get("/articles/1", [("", ""); 0]).unwrap();
+
+        for method in ["DELETE", "GET"] {
+            let request = server.request();
+
+            assert_eq!(request.method, method);
+            assert_eq!(request.header("x-api-key"), Some("secret"));
+            assert_eq!(request.header("accept"), Some(MIME));
+        }
+    }
+
+    #[test]
+    fn with_http_client_and_timeouts() {
+        let server = TestServer::start(vec![Reply::new(204)]);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("secret"));
+
+        let client = Client::with_http_client(
+            server.url(),
+            ReqClient::builder()
+                .default_headers(headers)
+                .build()
+                .unwrap(),
+        )
+        .timeouts(Timeouts {
+            connect: None,
+            read: Some(Duration::from_secs(5)),
+            total: Some(Duration::from_secs(5)),
+        })
+        .unwrap();
+
+        client.delete("/articles/1").unwrap();
+
+        assert_eq!(server.request().header("x-api-key"), Some("secret"));
+        assert!(matches!(
+            client.timeouts(Timeouts::new().connect(Duration::from_secs(5))),
+            Err(Error::UnsupportedConnectTimeout),
+        ));
+    }
+
+    #[test]
+    fn pages() {
+        let server = TestServer::start(vec![
+            Reply::document(
+                200,
+                fixtures::page_value(
+                    "1",
+                    Some(json!("/api/articles?page%5Bnumber%5D=2")),
+                ),
+            ),
+            Reply::document(
+                200,
+                fixtures::page_value(
+                    "2",
+                    Some(json!({ "href": "articles?page%5Bnumber%5D=3" })),
+                ),
+            ),
+            Reply::document(200, fixtures::page_value("3", None)),
+        ]);
+        let client = Client::new(format!("{}/api", server.url()));
+
+        let response = client
+            .get("/articles", &Query::new().page("number", 1))
+            .unwrap();
+        let pages: Vec<Response> =
+            client.pages(response).map(|page| page.unwrap()).collect();
+
+        assert_eq!(pages.len(), 3);
+        assert_eq!(
+            pages[2].document().unwrap(),
+            &serde_json::from_value(fixtures::page_value("3", None)).unwrap(),
+        );
+
+        for path in [
+            "/api/articles?page%5Bnumber%5D=1",
+            "/api/articles?page%5Bnumber%5D=2",
+            "/api/articles?page%5Bnumber%5D=3",
+        ] {
+            assert_eq!(server.request().path, path);
+        }
+    }
+
+    #[test]
+    fn pages_next_to_itself() {
+        let server = TestServer::start(vec![
+            Reply::document(
+                200,
+                fixtures::page_value("1", Some(json!("/articles?page=2"))),
+            ),
+            Reply::document(
+                200,
+                fixtures::page_value("2", Some(json!("/articles?page=2"))),
+            ),
+            Reply::document(
+                200,
+                json!({
+                    "data": [],
+                    "links": { "self": "/articles", "next": "/articles" },
+                }),
+            ),
+        ]);
+        let client = Client::new(server.url());
+
+        let response = client.get("/articles", [("", ""); 0]).unwrap();
+        let resources: Vec<Resource> = client
+            .pages(response)
+            .resources()
+            .map(|resource| resource.unwrap())
+            .collect();
+
+        assert_eq!(resources.len(), 2);
+
+        let response = client.get("/articles", [("", ""); 0]).unwrap();
+
+        assert_eq!(client.pages(response).count(), 1);
+    }
+
+    #[test]
+    fn pages_next_to_first() {
+        let server = TestServer::start(vec![
+            Reply::document(
+                200,
+                fixtures::page_value(
+                    "1",
+                    Some(json!("/articles?page%5Bnumber%5D=2")),
+                ),
+            ),
+            Reply::document(
+                200,
+                fixtures::page_value(
+                    "2",
+                    Some(json!("/articles?page%5Bnumber%5D=1")),
+                ),
+            ),
+        ]);
+        let client = Client::new(server.url());
+
+        let response = client
+            .get("/articles", &Query::new().page("number", 1))
+            .unwrap();
+        let resources: Vec<Resource> = client
+            .pages(response)
+            .resources()
+            .map(|resource| resource.unwrap())
+            .collect();
+
+        assert_eq!(resources.len(), 2);
+    }
+
+    #[test]
+    fn pages_resources_with_limit() {
+        let next = || Some(json!("/articles?page%5Bnumber%5D=2"));
+        let server = TestServer::start(vec![
+            Reply::document(200, fixtures::page_value("1", next())),
+            Reply::document(200, fixtures::page_value("2", next())),
+        ]);
+        let client = Client::new(server.url());
+
+        let response = client.get("/articles", [("", ""); 0]).unwrap();
+        let ids: Vec<String> = client
+            .pages(response)
+            .limit(2)
+            .resources()
+            .map(|resource| resource.unwrap().id.unwrap())
+            .collect();
+
+        assert_eq!(ids, ["1", "2"]);
+    }
+
+    #[test]
+    fn pages_error() {
+        let server = TestServer::start(vec![
+            Reply::document(
+                200,
+                fixtures::page_value("1", Some(json!("/articles?page=2"))),
+            ),
+            Reply::document(500, json!({ "errors": [{ "status": "500" }] })),
+        ]);
+        let client = Client::new(server.url());
+
+        let response = client.get("/articles", [("", ""); 0]).unwrap();
+        let mut pages = client.pages(response);
+
+        assert!(pages.next().unwrap().is_ok());
+        assert!(matches!(pages.next(), Some(Err(Error::Response(_)))));
+        assert!(pages.next().is_none());
+    }
+
+    #[test]
+    fn follow() {
+        let server = TestServer::start(vec![
+            Reply::document(200, fixtures::article_value()),
+            Reply::document(200, fixtures::article_value()),
+        ]);
+        let client = Client::new(format!("{}/api/", server.url()));
+
+        let response = client
+            .follow(&Link::String(format!("{}/articles/1", server.url())))
+            .unwrap();
+
+        assert_eq!(response.document().unwrap(), &fixtures::article());
+        assert_eq!(server.request().path, "/articles/1");
+
+        client
+            .follow(&Link::Object(LinkObject {
+                href: "articles/1".into(),
+                meta: None,
+            }))
+            .unwrap();
+
+        assert_eq!(server.request().path, "/api/articles/1");
+    }
+
+    #[test]
+    fn fetch_related() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            fixtures::article_value(),
+        )]);
+        let client = Client::new(server.url());
+
+        let response = client
+            .fetch_related(&fixtures::comment_with_related_link(), "article")
+            .unwrap();
+
+        assert_eq!(response.document().unwrap(), &fixtures::article());
+
+        let request = server.request();
+
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/comments/5/article");
+    }
+
+    #[test]
+    fn fetch_related_without_link() {
+        let client = Client::new("http://127.0.0.1:1");
+        let comment = fixtures::comment_with_related_link();
+
+        for name in ["author", "tags"] {
+            match client.fetch_related(&comment, name) {
+                Err(Error::NoRelatedLink(other)) => assert_eq!(other, name),
+                other => panic!("{:?}", other),
+            }
+        }
+    }
+
+    fn refreshing_auth(refreshes: Arc<AtomicUsize>) -> Auth {
+        Auth::provider(move |refresh: bool| {
+            let token = if refresh {
+                refreshes.fetch_add(1, Ordering::SeqCst);
+                "Bearer new"
+            } else {
+                "Bearer old"
+            };
+
+            let mut headers = HeaderMap::new();
+            headers.insert("authorization", HeaderValue::from_static(token));
+            headers
+        })
+    }
+
+    #[test]
+    fn auth() {
+        let server = TestServer::start(vec![
+            Reply::new(204),
+            Reply::document(200, fixtures::article_value()),
+        ]);
+
+        Client::new(server.url())
+            .auth(Auth::bearer("token"))
+            .delete("/articles/1")
+            .unwrap();
+
+        assert_eq!(
+            server.request().header("authorization"),
+            Some("Bearer token"),
+        );
+
+        Client::new(server.url())
+            .auth(Auth::basic("user", "pass"))
+            .get("/articles/1", [("", ""); 0])
+            .unwrap();
+
+        assert_eq!(
+            server.request().header("authorization"),
+            Some("Basic dXNlcjpwYXNz"),
+        );
+    }
+
+    #[test]
+    fn auth_refresh() {
+        let server = TestServer::start(vec![
+            Reply::new(401),
+            Reply::document(200, fixtures::article_value()),
+        ]);
+        let refreshes = Arc::new(AtomicUsize::new(0));
+
+        let response = Client::new(server.url())
+            .auth(refreshing_auth(refreshes.clone()))
+            .patch("/articles/1", &fixtures::article())
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Updated);
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+
+        for token in ["Bearer old", "Bearer new"] {
+            let request = server.request();
+
+            assert_eq!(request.method, "PATCH");
+            assert_eq!(request.header("authorization"), Some(token));
+            assert_eq!(request.json(), fixtures::article_value());
+        }
+    }
+
+    #[test]
+    fn auth_refresh_once() {
+        let server = TestServer::start(vec![
+            Reply::new(401),
+            Reply::document(401, json!({ "errors": [{ "status": "401" }] })),
+        ]);
+        let refreshes = Arc::new(AtomicUsize::new(0));
+
+        let result = Client::new(server.url())
+            .auth(refreshing_auth(refreshes.clone()))
+            .get("/articles/1", [("", ""); 0]);
+
+        assert!(matches!(result, Err(Error::Response(_))));
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn auth_invalid() {
+        let result = Client::new("http://127.0.0.1:1")
+            .auth(Auth::bearer("line\nbreak"))
+            .delete("/articles/1");
+
+        assert!(matches!(result, Err(Error::InvalidAuth(_))));
+    }
+
+    struct Recorder {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Middleware for Recorder {
+        fn request(&self, request: &mut Request) {
+            self.log.lock().unwrap().push(format!(
+                "{} {} {}",
+                self.name,
+                request.method,
+                request.url.path(),
+            ));
+            request
+                .headers
+                .append("x-middleware", HeaderValue::from_static(self.name));
+        }
+
+        fn response(
+            &self,
+            response: &RawResponse,
+            document: Option<&Document>,
+        ) {
+            self.log.lock().unwrap().push(format!(
+                "{} {} {}",
+                self.name,
+                response.status.as_u16(),
+                document.is_some(),
+            ));
+        }
+    }
+
+    #[test]
+    fn middleware() {
+        let server = TestServer::start(vec![
+            Reply::document(200, fixtures::article_value()),
+            Reply::document(404, json!({ "errors": [{ "status": "404" }] })),
+        ]);
+        let log = Arc::new(Mutex::new(vec![]));
+        let client = Client::new(server.url())
+            .middleware(Recorder {
+                name: "outer",
+                log: log.clone(),
+            })
+            .middleware(Recorder {
+                name: "inner",
+                log: log.clone(),
+            });
+
+        client.get("/articles/1", [("", ""); 0]).unwrap();
+        client.delete("/articles/2").unwrap_err();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "outer GET /articles/1",
+                "inner GET /articles/1",
+                "inner 200 true",
+                "outer 200 true",
+                "outer DELETE /articles/2",
+                "inner DELETE /articles/2",
+                "inner 404 true",
+                "outer 404 true",
+            ],
+        );
+
+        let request = server.request();
+        let values: Vec<&str> = request
+            .headers
+            .iter()
+            .filter(|(name, _)| name == "x-middleware")
+            .map(|(_, value)| value.as_str())
+            .collect();
+
+        assert_eq!(values, ["outer", "inner"]);
+    }
+
+    #[test]
+    fn middleware_with_auth_refresh() {
+        let server = TestServer::start(vec![Reply::new(401), Reply::new(204)]);
+        let log = Arc::new(Mutex::new(vec![]));
+
+        Client::new(server.url())
+            .auth(refreshing_auth(Arc::new(AtomicUsize::new(0))))
+            .middleware(Recorder {
+                name: "only",
+                log: log.clone(),
+            })
+            .delete("/articles/1")
+            .unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "only DELETE /articles/1",
+                "only DELETE /articles/1",
+                "only 204 false",
+            ],
+        );
+    }
+
+    fn unavailable_value() -> Value {
+        json!({ "errors": [{ "status": "503" }] })
+    }
+
+    fn fast_retry() -> RetryPolicy {
+        RetryPolicy::new()
+            .backoff(Duration::from_millis(1), Duration::from_millis(1))
+    }
+
+    #[test]
+    fn retry() {
+        let server = TestServer::start(vec![
+            Reply::new(503).header("Retry-After", "0"),
+            Reply::new(429)
+                .header("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT"),
+            Reply::document(200, fixtures::article_value()),
+        ]);
+
+        let response = Client::new(server.url())
+            .retry(RetryPolicy::new())
+            .get("/articles/1", [("", ""); 0])
+            .unwrap();
+
+        assert_eq!(response.document().unwrap(), &fixtures::article());
+
+        for _ in 0..3 {
+            assert_eq!(server.request().path, "/articles/1?");
+        }
+    }
+
+    #[test]
+    fn retry_with_backoff() {
+        let server = TestServer::start(vec![Reply::new(503), Reply::new(204)]);
+
+        let response = Client::new(server.url())
+            .retry(fast_retry())
+            .delete("/articles/1")
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::DeletedWithoutContent);
+    }
+
+    #[test]
+    fn retry_gives_up() {
+        let server = TestServer::start(vec![
+            Reply::new(503),
+            Reply::document(503, unavailable_value()),
+        ]);
+
+        let result = Client::new(server.url())
+            .retry(fast_retry().max_attempts(2))
+            .delete("/articles/1");
+
+        match result {
+            Err(Error::Response(response)) => {
+                assert_eq!(response.outcome(), Outcome::Failed)
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn retry_after_too_long() {
+        let server = TestServer::start(vec![
+            Reply::new(429).header("Retry-After", "86400"),
+            Reply::new(204),
+        ]);
+        let start = Instant::now();
+
+        let result = Client::new(server.url())
+            .retry(RetryPolicy::new())
+            .delete("/articles/1");
+
+        match result {
+            Err(Error::Response(response)) => {
+                assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS)
+            }
+            other => panic!("{:?}", other),
+        }
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn retry_gives_up_without_document() {
+        let server = TestServer::start(vec![
+            Reply::new(503),
+            Reply::new(503),
+            Reply::new(503),
+        ]);
+
+        let result = Client::new(server.url())
+            .retry(fast_retry().max_attempts(3))
+            .get("/articles/1", [("", ""); 0]);
+
+        match result {
+            Err(Error::Response(response)) => {
+                assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+                assert_eq!(response.document(), None);
+            }
+            other => panic!("{:?}", other),
+        }
+
+        for _ in 0..3 {
+            assert_eq!(server.request().method, "GET");
+        }
+    }
+
+    #[test]
+    fn retry_only_idempotent() {
+        let server = TestServer::start(vec![
+            Reply::document(503, unavailable_value()),
+            Reply::new(503),
+            Reply::document(201, fixtures::article_value()),
+        ]);
+
+        let result = Client::new(server.url())
+            .retry(fast_retry())
+            .post("/articles", &fixtures::article());
+
+        assert!(matches!(result, Err(Error::Response(_))));
+
+        let response = Client::new(server.url())
+            .retry(fast_retry().all_methods(true))
+            .post("/articles", &fixtures::article())
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Created);
+
+        for _ in 0..3 {
+            let request = server.request();
+
+            assert_eq!(request.method, "POST");
+            assert_eq!(request.json(), fixtures::article_value());
+        }
+    }
+
+    #[test]
+    fn timeouts() {
+        let server = TestServer::start(vec![
+            Reply::new(204).delay(Duration::from_millis(500)),
+            Reply::new(204).delay(Duration::from_millis(500)),
+            Reply::new(204).delay(Duration::from_millis(200)),
+        ]);
+        let client = Client::new(server.url())
+            .timeouts(Timeouts::new().read(Duration::from_millis(50)))
+            .unwrap();
+
+        assert!(matches!(client.delete("/articles/1"), Err(Error::Timeout)));
+
+        let client = Client::new(server.url())
+            .timeouts(Timeouts::new().total(Duration::from_millis(50)))
+            .unwrap();
+
+        assert!(matches!(client.delete("/articles/1"), Err(Error::Timeout)));
+
+        let response = client
+            .with_timeouts(Timeouts::new().total(Duration::from_secs(5)))
+            .unwrap()
+            .delete("/articles/1")
+            .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::DeletedWithoutContent);
+        assert!(matches!(
+            client
+                .with_timeouts(Timeouts::new().connect(Duration::from_secs(1))),
+            Err(Error::UnsupportedConnectTimeout),
+        ));
+    }
+
+    #[test]
+    fn total_timeout_with_retries() {
+        let server = TestServer::start(vec![
+            Reply::new(503).delay(Duration::from_millis(150)),
+            Reply::new(503).delay(Duration::from_millis(150)),
+            Reply::new(204),
+        ]);
+        let client = Client::new(server.url())
+            .retry(fast_retry())
+            .timeouts(Timeouts::new().total(Duration::from_millis(250)))
+            .unwrap();
+
+        assert!(matches!(client.delete("/articles/1"), Err(Error::Timeout)));
+    }
+
+    #[test]
+    fn total_timeout_before_backoff() {
+        let server = TestServer::start(vec![
+            Reply::new(503).header("Retry-After", "5"),
+            Reply::new(204),
+        ]);
+        let client = Client::new(server.url())
+            .retry(RetryPolicy::new())
+            .timeouts(Timeouts::new().total(Duration::from_secs(1)))
+            .unwrap();
+
+        let start = Instant::now();
+
+        assert!(matches!(client.delete("/articles/1"), Err(Error::Timeout)));
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn connect_timeout() {
+        let server = UnresponsiveServer::start();
+        let client = Client::new(server.url())
+            .timeouts(Timeouts::new().connect(Duration::from_millis(100)))
+            .unwrap();
+
+        let start = Instant::now();
+
+        assert!(matches!(client.delete("/articles/1"), Err(Error::Timeout)));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn response_accessors() {
+        let server = TestServer::start(vec![
+            Reply::document(200, fixtures::article_value())
+                .header("ETag", "\"v1\"")
+                .header("X-RateLimit-Remaining", "41"),
+            Reply::new(304).header("ETag", "\"v1\""),
+            Reply::document(404, json!({ "errors": [{ "status": "404" }] })),
+        ]);
+        let client = Client::new(server.url());
+
+        let response = client.get("/articles/1", [("", ""); 0]).unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["etag"], "\"v1\"");
+        assert_eq!(response.headers()["x-ratelimit-remaining"], "41");
+        assert_eq!(
+            response
+                .body()
+                .map(serde_json::from_slice::<Value>)
+                .unwrap()
+                .unwrap(),
+            fixtures::article_value(),
+        );
+        assert_eq!(response.location(), None);
+
+        let response = client.get("/articles/1", [("", ""); 0]).unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()["etag"], "\"v1\"");
+        assert_eq!(response.body(), None);
+
+        match client.get("/articles/2", [("", ""); 0]) {
+            Err(Error::Response(response)) => {
+                assert_eq!(response.status(), StatusCode::NOT_FOUND);
+                assert_eq!(
+                    response.text().as_deref(),
+                    Some(r#"{"errors":[{"status":"404"}]}"#),
+                );
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+}
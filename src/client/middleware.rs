@@ -0,0 +1,38 @@
+use super::*;
+
+use std::fmt::{self, Debug, Formatter};
+
+// Cross-cutting behavior of a client, e.g. request IDs, logging or metrics.
+// Middleware sees requests in the order in which it was added to the client
+// and responses in the reverse order.
+pub trait Middleware: Send + Sync {
+    // Called before a request is sent, also when it's retried.
+    fn request(&self, _request: &mut Request) {}
+
+    // Called with the response which the client returns and the document
+    // parsed from it, also when the response is returned as an error.
+    fn response(&self, _response: &RawResponse, _document: Option<&Document>) {}
+}
+
+// Outgoing request, with all headers set by the client.
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+// Incoming response, before the document is parsed from its body.
+#[derive(Clone, Debug)]
+pub struct RawResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+impl Debug for dyn Middleware {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("Middleware")
+    }
+}
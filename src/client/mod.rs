@@ -0,0 +1,607 @@
+mod auth;
+#[cfg(feature = "client")]
+mod blocking;
+mod middleware;
+#[cfg(feature = "client-async")]
+mod nonblocking;
+mod retry;
+mod timeouts;
+
+pub use auth::{Auth, AuthProvider};
+#[cfg(feature = "client")]
+pub use blocking::{Client, Pages};
+pub use middleware::{Middleware, RawResponse, Request};
+#[cfg(feature = "client-async")]
+pub use nonblocking::{AsyncClient, AsyncPages};
+pub use retry::RetryPolicy;
+pub use timeouts::Timeouts;
+
+use super::*;
+
+use std::{
+    collections::HashSet, fmt::Display, str::Utf8Error, sync::Arc,
+    time::Duration,
+};
+
+use encoding_rs::{Encoding, UTF_8};
+use mime::Mime;
+use reqwest::{
+    header::{
+        HeaderMap, HeaderValue, InvalidHeaderValue, ACCEPT, CONTENT_TYPE,
+        LOCATION,
+    },
+    Error as ReqError, Method, StatusCode, Url,
+};
+use serde::Serialize;
+use serde_json::Error as JsonError;
+use url::ParseError;
+
+const MIME: &str = "application/vnd.api+json";
+const MIME_PREFIX: &str = "application/vnd.api+json;";
+
+pub type Result = std::result::Result<Response, Error>;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Response {
+    #[serde(skip)]
+    url: Url,
+    outcome: Outcome,
+    document: Option<Document>,
+    location: Option<String>,
+    #[serde(skip)]
+    status: StatusCode,
+    #[serde(skip)]
+    headers: HeaderMap,
+    #[serde(skip)]
+    body: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum Outcome {
+    Fetched,
+    NotModified,
+    Created,
+    CreatedWithoutContent,
+    Updated,
+    UpdatedWithoutContent,
+    Deleted,
+    DeletedWithoutContent,
+    Accepted,
+    // The server responded with 4xx or 5xx status code. Such responses are
+    // returned as `Error::Response`.
+    Failed,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Response(Box<Response>),
+    Url(ParseError),
+    Http(ReqError),
+    Timeout,
+    // The connect timeout can't be set on the HTTP client given by the
+    // caller or for some requests only.
+    UnsupportedConnectTimeout,
+    InvalidStatus(StatusCode),
+    NoContentType,
+    InvalidContentType(HeaderValue),
+    InvalidLocationUtf8(Utf8Error),
+    InvalidAuth(InvalidHeaderValue),
+    NoRelatedLink(String),
+    Text(ReqError),
+    Json(JsonError),
+    Validation(ValidationReport),
+}
+
+// Settings and response handling which are shared by the blocking and the
+// async clients.
+#[derive(Clone, Debug)]
+struct Base {
+    url: String,
+    add_json_ext: bool,
+    strict: bool,
+    auth: Option<Auth>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    retry: Option<RetryPolicy>,
+    timeouts: Timeouts,
+}
+
+// State of the pagers of both clients, which follow "next" links starting
+// from the given response (https://jsonapi.org/format/#fetching-pagination).
+#[derive(Clone, Debug)]
+struct Pager {
+    first: Option<Response>,
+    next: Option<Link>,
+    // URLs of the pages which were already fetched, so that a "next" link
+    // back to one of them ends the pagination rather than looping forever.
+    visited: HashSet<Url>,
+    limit: Option<usize>,
+    count: usize,
+}
+
+enum Page {
+    Ready(Box<Response>),
+    Fetch(Link),
+}
+
+// Top-level document of requests to relationship endpoints, which contains
+// only resource linkage.
+#[derive(Serialize)]
+struct Linkage<D: Serialize> {
+    data: D,
+}
+
+impl Response {
+    pub fn outcome(&self) -> Outcome {
+        self.outcome
+    }
+
+    pub fn document(&self) -> Option<&Document> {
+        self.document.as_ref()
+    }
+
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    // URL of the request which the response was received for.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    // Body as it was received, there is none for 204 and 304 responses.
+    pub fn body(&self) -> Option<&[u8]> {
+        self.body.as_deref()
+    }
+
+    // Body decoded by the charset of the content type, as it is when the
+    // document is read.
+    pub fn text(&self) -> Option<String> {
+        let body = self.body.as_ref()?;
+
+        Some(Base::decode_body(&self.headers, body))
+    }
+
+    // Primary data of a response from a relationship endpoint. Resource
+    // identifiers are deserialized as resources in `Document`, so here they
+    // are converted back.
+    pub fn linkage(&self) -> Option<IdentifierData> {
+        let identifier = |resource: &Resource| ResourceIdentifier {
+            meta: resource.meta.clone(),
+            ..resource.into()
+        };
+
+        Some(match self.document.as_ref()?.data.as_ref()? {
+            Data::Null => IdentifierData::Null,
+            Data::Single(resource) => {
+                IdentifierData::Single(identifier(resource))
+            }
+            Data::Multiple(resources) => IdentifierData::Multiple(
+                resources.iter().map(identifier).collect(),
+            ),
+        })
+    }
+}
+
+impl Response {
+    fn self_link(&self) -> Option<&Link> {
+        self.document.as_ref()?.links.as_ref()?.self_.as_ref()
+    }
+
+    fn next_link(&self) -> Option<&Link> {
+        self.document.as_ref()?.links.as_ref()?.next.as_ref()
+    }
+
+    fn into_resources(self) -> Vec<Resource> {
+        match self.document.and_then(|document| document.data) {
+            None | Some(Data::Null) => vec![],
+            Some(Data::Single(resource)) => vec![resource],
+            Some(Data::Multiple(resources)) => resources,
+        }
+    }
+}
+
+impl Pager {
+    fn new(response: Response) -> Self {
+        Self {
+            first: Some(response),
+            next: None,
+            visited: HashSet::new(),
+            limit: None,
+            count: 0,
+        }
+    }
+
+    fn next(&mut self) -> Option<Page> {
+        if self.limit.is_some_and(|limit| self.count >= limit) {
+            return None;
+        }
+
+        let page = match self.first.take() {
+            Some(response) => Page::Ready(Box::new(response)),
+            None => Page::Fetch(self.next.take()?),
+        };
+
+        self.count += 1;
+
+        Some(page)
+    }
+
+    // Links are compared once resolved, as the URL of the first page is the
+    // one it was requested with rather than a link of a document.
+    fn fetched(&mut self, base: &Base, result: &Result) {
+        self.next = match result {
+            Err(_) => None,
+            Ok(response) => {
+                self.visited.insert(response.url.clone());
+
+                if let Some(url) = response
+                    .self_link()
+                    .and_then(|link| base.url_for_link(link).ok())
+                {
+                    self.visited.insert(url);
+                }
+
+                response
+                    .next_link()
+                    .filter(|link| {
+                        base.url_for_link(link)
+                            .map_or(true, |url| !self.visited.contains(&url))
+                    })
+                    .cloned()
+            }
+        };
+    }
+
+    fn resources(result: Result) -> Vec<std::result::Result<Resource, Error>> {
+        match result {
+            Err(error) => vec![Err(error)],
+            Ok(response) => {
+                response.into_resources().into_iter().map(Ok).collect()
+            }
+        }
+    }
+}
+
+impl Outcome {
+    // https://jsonapi.org/format/#fetching-resources-responses
+    // https://jsonapi.org/format/#fetching-relationships-responses
+    fn fetching(status: StatusCode) -> Option<Self> {
+        match status {
+            StatusCode::OK => Some(Self::Fetched),
+            StatusCode::NOT_MODIFIED => Some(Self::NotModified),
+            _ => None,
+        }
+    }
+
+    // https://jsonapi.org/format/#crud-creating-responses
+    fn creating(status: StatusCode) -> Option<Self> {
+        match status {
+            StatusCode::OK | StatusCode::CREATED => Some(Self::Created),
+            StatusCode::ACCEPTED => Some(Self::Accepted),
+            StatusCode::NO_CONTENT => Some(Self::CreatedWithoutContent),
+            _ => None,
+        }
+    }
+
+    // https://jsonapi.org/format/#crud-updating-responses
+    fn updating(status: StatusCode) -> Option<Self> {
+        match status {
+            StatusCode::OK => Some(Self::Updated),
+            StatusCode::ACCEPTED => Some(Self::Accepted),
+            StatusCode::NO_CONTENT => Some(Self::UpdatedWithoutContent),
+            _ => None,
+        }
+    }
+
+    // https://jsonapi.org/format/#crud-deleting-responses
+    fn deleting(status: StatusCode) -> Option<Self> {
+        match status {
+            StatusCode::OK => Some(Self::Deleted),
+            StatusCode::ACCEPTED => Some(Self::Accepted),
+            StatusCode::NO_CONTENT => Some(Self::DeletedWithoutContent),
+            _ => None,
+        }
+    }
+}
+
+impl Base {
+    fn new(url: String) -> Self {
+        Self {
+            url,
+            add_json_ext: false,
+            strict: false,
+            auth: None,
+            middleware: vec![],
+            retry: None,
+            timeouts: Timeouts::new(),
+        }
+    }
+
+    fn url_for_get<P, I, K, V>(
+        &self,
+        path: P,
+        params: I,
+    ) -> std::result::Result<Url, Error>
+    where
+        P: Display,
+        I: IntoIterator,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        <I as IntoIterator>::Item: std::borrow::Borrow<(K, V)>,
+    {
+        Url::parse_with_params(
+            &if self.add_json_ext {
+                format!("{}{}.json", self.url, path)
+            } else {
+                format!("{}{}", self.url, path)
+            },
+            params,
+        )
+        .map_err(Error::Url)
+    }
+
+    fn url_for<P>(&self, path: P) -> std::result::Result<Url, Error>
+    where
+        P: Display,
+    {
+        Url::parse(&if self.add_json_ext {
+            format!("{}{}.json", self.url, path)
+        } else {
+            format!("{}{}", self.url, path)
+        })
+        .map_err(Error::Url)
+    }
+
+    fn url_for_relationship<P, N>(
+        &self,
+        path: P,
+        name: N,
+    ) -> std::result::Result<Url, Error>
+    where
+        P: Display,
+        N: Display,
+    {
+        self.url_for(format!("{}/relationships/{}", path, name))
+    }
+
+    // Links are resolved like relative references, with the base URL
+    // treated as a directory.
+    fn url_for_link(&self, link: &Link) -> std::result::Result<Url, Error> {
+        Url::parse(&format!("{}/", self.url.trim_end_matches('/')))
+            .and_then(|url| url.join(link.href()))
+            .map_err(Error::Url)
+    }
+
+    fn related_link<'r>(
+        resource: &'r Resource,
+        name: &str,
+    ) -> std::result::Result<&'r Link, Error> {
+        resource
+            .relationships
+            .as_ref()
+            .and_then(|relationships| relationships.get(name))
+            .and_then(|relationship| relationship.links.as_ref())
+            .and_then(|links| links.related.as_ref())
+            .ok_or_else(|| Error::NoRelatedLink(name.into()))
+    }
+
+    // Only a provider can refresh credentials, so only then a request which
+    // is rejected with 401 Unauthorized is worth retrying.
+    fn can_refresh_auth(&self) -> bool {
+        self.auth.as_ref().is_some_and(Auth::can_refresh)
+    }
+
+    fn auth_headers(
+        &self,
+        refresh: bool,
+    ) -> std::result::Result<HeaderMap, Error> {
+        match &self.auth {
+            None => Ok(HeaderMap::new()),
+            Some(auth) => auth.headers(refresh).map_err(Error::InvalidAuth),
+        }
+    }
+
+    fn retry_delay(
+        &self,
+        method: &Method,
+        status: StatusCode,
+        headers: &HeaderMap,
+        attempt: u32,
+    ) -> Option<Duration> {
+        self.retry.as_ref()?.delay(method, status, headers, attempt)
+    }
+
+    fn document_body(
+        document: &Document,
+    ) -> std::result::Result<Vec<u8>, Error> {
+        serde_json::to_vec(document).map_err(Error::Json)
+    }
+
+    fn linkage_body<D: Serialize>(
+        data: D,
+    ) -> std::result::Result<Vec<u8>, Error> {
+        serde_json::to_vec(&Linkage { data }).map_err(Error::Json)
+    }
+
+    // These responses can't have a body, so there is neither content type
+    // nor document to check.
+    fn has_body(status: StatusCode) -> bool {
+        status != StatusCode::NO_CONTENT && status != StatusCode::NOT_MODIFIED
+    }
+
+    // The charset of the content type is respected, like the HTTP client
+    // does, so that both clients read the same document from the same body.
+    fn decode_body(headers: &HeaderMap, body: &[u8]) -> String {
+        let encoding = headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<Mime>().ok())
+            .and_then(|mime| {
+                Encoding::for_label(
+                    mime.get_param(mime::CHARSET)?.as_str().as_bytes(),
+                )
+            })
+            .unwrap_or(UTF_8);
+
+        encoding.decode(body).0.into_owned()
+    }
+
+    fn is_json_api(headers: &HeaderMap) -> bool {
+        headers.get(CONTENT_TYPE).is_some_and(|content_type| {
+            content_type == MIME
+                || content_type.as_bytes().starts_with(MIME_PREFIX.as_bytes())
+        })
+    }
+
+    fn request(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<Vec<u8>>,
+        refresh_auth: bool,
+    ) -> std::result::Result<Request, Error> {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static(MIME));
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(MIME));
+        headers.extend(self.auth_headers(refresh_auth)?);
+
+        let mut request = Request {
+            method,
+            url,
+            headers,
+            body,
+        };
+
+        for middleware in &self.middleware {
+            middleware.request(&mut request);
+        }
+
+        Ok(request)
+    }
+
+    fn finish(
+        &self,
+        url: Url,
+        raw: RawResponse,
+        outcome: fn(StatusCode) -> Option<Outcome>,
+    ) -> Result {
+        let result = self.response(url, &raw, outcome);
+
+        let document = match &result {
+            Ok(response) => response.document(),
+            Err(Error::Response(response)) => response.document(),
+            Err(_) => None,
+        };
+
+        for middleware in self.middleware.iter().rev() {
+            middleware.response(&raw, document);
+        }
+
+        result
+    }
+
+    // The status is checked first, so that every 4xx or 5xx response is
+    // returned as `Error::Response`, whatever its headers and body are.
+    fn response(
+        &self,
+        url: Url,
+        raw: &RawResponse,
+        outcome: fn(StatusCode) -> Option<Outcome>,
+    ) -> Result {
+        let failed =
+            raw.status.is_client_error() || raw.status.is_server_error();
+
+        let outcome = if failed {
+            Outcome::Failed
+        } else {
+            outcome(raw.status).ok_or(Error::InvalidStatus(raw.status))?
+        };
+
+        let location = match raw.headers.get(LOCATION) {
+            None => None,
+            Some(header) => match std::str::from_utf8(header.as_bytes()) {
+                Err(_) if failed => None,
+                Err(error) => return Err(Error::InvalidLocationUtf8(error)),
+                Ok(location) => Some(location.to_string()),
+            },
+        };
+
+        let text = raw
+            .body
+            .as_ref()
+            .map(|body| Self::decode_body(&raw.headers, body));
+
+        let document = match &text {
+            None => None,
+            Some(body) if failed => self.error_document(&raw.headers, body),
+            // E.g. 202 Accepted or 201 Created with only a "Location" header,
+            // the specification doesn't require a document for them.
+            Some(body) if body.is_empty() => None,
+            Some(body) => Some(self.read_document(&raw.headers, body)?),
+        };
+
+        let response = Response {
+            url,
+            outcome,
+            document,
+            location,
+            status: raw.status,
+            headers: raw.headers.clone(),
+            body: raw.body.clone(),
+        };
+
+        if failed {
+            Err(Error::Response(Box::new(response)))
+        } else {
+            Ok(response)
+        }
+    }
+
+    // Error responses may come from a proxy rather than the server, so they
+    // are expected to have a document only if it's declared, and a document
+    // which can't be read is ignored.
+    fn error_document(
+        &self,
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Option<Document> {
+        if body.is_empty() || !Self::is_json_api(headers) {
+            return None;
+        }
+
+        self.read_document(headers, body).ok()
+    }
+
+    fn read_document(
+        &self,
+        headers: &HeaderMap,
+        body: &str,
+    ) -> std::result::Result<Document, Error> {
+        let content_type =
+            headers.get(CONTENT_TYPE).ok_or(Error::NoContentType)?;
+
+        if !Self::is_json_api(headers) {
+            return Err(Error::InvalidContentType(content_type.clone()));
+        }
+
+        let document = serde_json::from_str(body).map_err(Error::Json)?;
+
+        if self.strict {
+            let report = validate(&document);
+
+            if !report.is_valid() {
+                return Err(Error::Validation(report));
+            }
+        }
+
+        Ok(document)
+    }
+}
@@ -0,0 +1,1085 @@
+use super::*;
+
+use std::future::Future;
+use std::time::Instant;
+
+use reqwest::{Client as ReqClient, Response as ReqResponse};
+
+#[derive(Clone, Debug)]
+pub struct AsyncClient {
+    base: Base,
+    http: ReqClient,
+    // Whether the HTTP client was given by the caller rather than built here.
+    custom_http: bool,
+}
+
+// Same as `Pages`, but the pages are fetched with `next().await`.
+#[derive(Debug)]
+pub struct AsyncPages<'c> {
+    client: &'c AsyncClient,
+    pager: Pager,
+}
+
+impl AsyncClient {
+    pub fn new<U: Into<String>>(url: U) -> Self {
+        Self {
+            custom_http: false,
+            ..Self::with_http_client(url, ReqClient::new())
+        }
+    }
+
+    // The HTTP client is reused for all requests, so it should be configured
+    // (proxies, TLS, default headers, connect timeout and so on) beforehand.
+    pub fn with_http_client<U: Into<String>>(url: U, http: ReqClient) -> Self {
+        Self {
+            base: Base::new(url.into()),
+            http,
+            custom_http: true,
+        }
+    }
+
+    pub fn add_json_ext(self, add_json_ext: bool) -> Self {
+        Self {
+            base: Base {
+                add_json_ext,
+                ..self.base
+            },
+            ..self
+        }
+    }
+
+    // Rejects responses which violate MUST requirements of the specification.
+    pub fn strict(self, strict: bool) -> Self {
+        Self {
+            base: Base {
+                strict,
+                ..self.base
+            },
+            ..self
+        }
+    }
+
+    pub fn auth(self, auth: Auth) -> Self {
+        Self {
+            base: Base {
+                auth: Some(auth),
+                ..self.base
+            },
+            ..self
+        }
+    }
+
+    // Middleware is applied in the order in which it is added.
+    pub fn middleware<M: Middleware + 'static>(self, middleware: M) -> Self {
+        let mut base = self.base;
+        base.middleware.push(Arc::new(middleware));
+
+        Self { base, ..self }
+    }
+
+    pub fn retry(self, retry: RetryPolicy) -> Self {
+        Self {
+            base: Base {
+                retry: Some(retry),
+                ..self.base
+            },
+            ..self
+        }
+    }
+
+    // Default timeouts of requests, the ones which are set replace the
+    // current ones. The connect timeout is a setting of the HTTP client, so
+    // it's only supported with the default one, which is rebuilt when it
+    // changes. Set it on the HTTP client given to `with_http_client` instead.
+    pub fn timeouts(
+        self,
+        timeouts: Timeouts,
+    ) -> std::result::Result<Self, Error> {
+        let timeouts = self.base.timeouts.merge(timeouts);
+
+        let http = match timeouts.connect {
+            Some(_) if self.custom_http => {
+                return Err(Error::UnsupportedConnectTimeout)
+            }
+            Some(connect) if timeouts.connect != self.base.timeouts.connect => {
+                ReqClient::builder()
+                    .connect_timeout(connect)
+                    .build()
+                    .map_err(Error::Http)?
+            }
+            _ => self.http,
+        };
+
+        Ok(Self {
+            base: Base {
+                timeouts,
+                ..self.base
+            },
+            http,
+            ..self
+        })
+    }
+
+    // Client for some requests with other read and total timeouts, which
+    // shares the HTTP client and so its connection pool with this one.
+    pub fn with_timeouts(
+        &self,
+        timeouts: Timeouts,
+    ) -> std::result::Result<Self, Error> {
+        if timeouts.connect.is_some() {
+            return Err(Error::UnsupportedConnectTimeout);
+        }
+
+        Ok(Self {
+            base: Base {
+                timeouts: self.base.timeouts.merge(timeouts),
+                ..self.base.clone()
+            },
+            ..self.clone()
+        })
+    }
+
+    pub async fn get<P, I, K, V>(&self, path: P, params: I) -> Result
+    where
+        P: Display,
+        I: IntoIterator,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        <I as IntoIterator>::Item: std::borrow::Borrow<(K, V)>,
+    {
+        let url = self.base.url_for_get(path, params)?;
+
+        self.make_request(Method::GET, url, None, Outcome::fetching)
+            .await
+    }
+
+    pub async fn post<'d, P, D>(&self, path: P, document: D) -> Result
+    where
+        P: Display,
+        D: Into<&'d Document>,
+    {
+        let url = self.base.url_for(path)?;
+        let body = Base::document_body(document.into())?;
+
+        self.make_request(Method::POST, url, Some(body), Outcome::creating)
+            .await
+    }
+
+    pub async fn patch<'d, P, D>(&self, path: P, document: D) -> Result
+    where
+        P: Display,
+        D: Into<&'d Document>,
+    {
+        let url = self.base.url_for(path)?;
+        let body = Base::document_body(document.into())?;
+
+        self.make_request(Method::PATCH, url, Some(body), Outcome::updating)
+            .await
+    }
+
+    pub async fn delete<P>(&self, path: P) -> Result
+    where
+        P: Display,
+    {
+        let url = self.base.url_for(path)?;
+
+        self.make_request(Method::DELETE, url, None, Outcome::deleting)
+            .await
+    }
+
+    // https://jsonapi.org/format/#fetching-relationships
+    pub async fn get_relationship<P, N>(&self, path: P, name: N) -> Result
+    where
+        P: Display,
+        N: Display,
+    {
+        let url = self.base.url_for_relationship(path, name)?;
+
+        self.make_request(Method::GET, url, None, Outcome::fetching)
+            .await
+    }
+
+    // https://jsonapi.org/format/#crud-updating-relationships
+    pub async fn update_relationship<'d, P, N, D>(
+        &self,
+        path: P,
+        name: N,
+        data: D,
+    ) -> Result
+    where
+        P: Display,
+        N: Display,
+        D: Into<&'d IdentifierData>,
+    {
+        self.send_linkage(Method::PATCH, path, name, data.into())
+            .await
+    }
+
+    pub async fn add_to_relationship<P, N>(
+        &self,
+        path: P,
+        name: N,
+        identifiers: &[ResourceIdentifier],
+    ) -> Result
+    where
+        P: Display,
+        N: Display,
+    {
+        self.send_linkage(Method::POST, path, name, identifiers)
+            .await
+    }
+
+    pub async fn remove_from_relationship<P, N>(
+        &self,
+        path: P,
+        name: N,
+        identifiers: &[ResourceIdentifier],
+    ) -> Result
+    where
+        P: Display,
+        N: Display,
+    {
+        self.send_linkage(Method::DELETE, path, name, identifiers)
+            .await
+    }
+
+    async fn send_linkage<P, N, D>(
+        &self,
+        method: Method,
+        path: P,
+        name: N,
+        data: D,
+    ) -> Result
+    where
+        P: Display,
+        N: Display,
+        D: Serialize,
+    {
+        let url = self.base.url_for_relationship(path, name)?;
+        let body = Base::linkage_body(data)?;
+
+        // https://jsonapi.org/format/#crud-updating-relationship-responses
+        self.make_request(method, url, Some(body), Outcome::updating)
+            .await
+    }
+
+    pub fn pages(&self, response: Response) -> AsyncPages<'_> {
+        AsyncPages {
+            client: self,
+            pager: Pager::new(response),
+        }
+    }
+
+    // Fetches a link of a document, e.g. "self" or "related" link.
+    pub async fn follow(&self, link: &Link) -> Result {
+        let url = self.base.url_for_link(link)?;
+
+        self.make_request(Method::GET, url, None, Outcome::fetching)
+            .await
+    }
+
+    // https://jsonapi.org/format/#document-resource-object-related-resource-links
+    pub async fn fetch_related(
+        &self,
+        resource: &Resource,
+        name: &str,
+    ) -> Result {
+        self.follow(Base::related_link(resource, name)?).await
+    }
+
+    async fn make_request(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<Vec<u8>>,
+        outcome: fn(StatusCode) -> Option<Outcome>,
+    ) -> Result {
+        let start = Instant::now();
+        let mut attempt = 1;
+
+        let response = loop {
+            let response =
+                self.send_authorized(&method, &url, &body, start).await?;
+
+            match self.base.retry_delay(
+                &method,
+                response.status(),
+                response.headers(),
+                attempt,
+            ) {
+                None => break response,
+                Some(delay) => {
+                    self.base.timeouts.check_total(start, delay)?;
+                    tokio::time::sleep(delay).await;
+                }
+            }
+
+            attempt += 1;
+        };
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let body = if Base::has_body(status) {
+            Some(self.read_body(response).await?)
+        } else {
+            None
+        };
+
+        self.base.finish(
+            url,
+            RawResponse {
+                status,
+                headers,
+                body,
+            },
+            outcome,
+        )
+    }
+
+    // Credentials are refreshed and the request is retried once when it is
+    // rejected with 401 Unauthorized.
+    async fn send_authorized(
+        &self,
+        method: &Method,
+        url: &Url,
+        body: &Option<Vec<u8>>,
+        start: Instant,
+    ) -> std::result::Result<ReqResponse, Error> {
+        let request = self.base.request(
+            method.clone(),
+            url.clone(),
+            body.clone(),
+            false,
+        )?;
+        let response = self.send(request, start).await?;
+
+        if response.status() == StatusCode::UNAUTHORIZED
+            && self.base.can_refresh_auth()
+        {
+            let request = self.base.request(
+                method.clone(),
+                url.clone(),
+                body.clone(),
+                true,
+            )?;
+
+            return self.send(request, start).await;
+        }
+
+        Ok(response)
+    }
+
+    async fn send(
+        &self,
+        request: Request,
+        start: Instant,
+    ) -> std::result::Result<ReqResponse, Error> {
+        let mut request_builder = self
+            .http
+            .request(request.method, request.url)
+            .headers(request.headers);
+
+        if let Some(body) = request.body {
+            request_builder = request_builder.body(body);
+        }
+
+        if let Some(total) = self.base.timeouts.total_left(start) {
+            request_builder = request_builder.timeout(total);
+        }
+
+        within(self.base.timeouts.read, request_builder.send())
+            .await?
+            .map_err(Error::from_http)
+    }
+
+    // The read timeout applies to every chunk of the body.
+    async fn read_body(
+        &self,
+        mut response: ReqResponse,
+    ) -> std::result::Result<Vec<u8>, Error> {
+        let mut body = vec![];
+
+        while let Some(chunk) =
+            within(self.base.timeouts.read, response.chunk())
+                .await?
+                .map_err(Error::from_text)?
+        {
+            body.extend_from_slice(&chunk);
+        }
+
+        Ok(body)
+    }
+}
+
+async fn within<F: Future>(
+    timeout: Option<Duration>,
+    future: F,
+) -> std::result::Result<F::Output, Error> {
+    match timeout {
+        None => Ok(future.await),
+        Some(timeout) => tokio::time::timeout(timeout, future)
+            .await
+            .map_err(|_| Error::Timeout),
+    }
+}
+
+impl AsyncPages<'_> {
+    // Maximum number of pages, including the initial one.
+    pub fn limit(self, limit: usize) -> Self {
+        Self {
+            pager: Pager {
+                limit: Some(limit),
+                ..self.pager
+            },
+            ..self
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub async fn next(&mut self) -> Option<Result> {
+        let result = match self.pager.next()? {
+            Page::Ready(response) => Ok(*response),
+            Page::Fetch(link) => self.client.follow(&link).await,
+        };
+
+        self.pager.fetched(&self.client.base, &result);
+
+        Some(result)
+    }
+
+    // Resources of the primary data across all pages.
+    pub async fn resources(
+        mut self,
+    ) -> std::result::Result<Vec<Resource>, Error> {
+        let mut resources = vec![];
+
+        while let Some(result) = self.next().await {
+            for resource in Pager::resources(result) {
+                resources.push(resource?);
+            }
+        }
+
+        Ok(resources)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::test_server::{Reply, TestServer, UnresponsiveServer, MIME};
+
+    use serde_json::json;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn get() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            fixtures::article_value(),
+        )]);
+
+        let response = block_on(
+            AsyncClient::new(server.url())
+                .get("/articles/1", [("include", "author")]),
+        )
+        .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Fetched);
+        assert_eq!(response.document().unwrap(), &fixtures::article());
+
+        let request = server.request();
+
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/articles/1?include=author");
+        assert_eq!(request.header("accept"), Some(MIME));
+    }
+
+    #[test]
+    fn get_with_query() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            fixtures::article_value(),
+        )]);
+
+        let query = Query::new().include("author").sort("title");
+
+        block_on(AsyncClient::new(server.url()).get("/articles", &query))
+            .unwrap();
+
+        assert_eq!(
+            server.request().path,
+            "/articles?include=author&sort=title",
+        );
+    }
+
+    #[test]
+    fn post() {
+        let server = TestServer::start(vec![Reply::document(
+            201,
+            fixtures::article_value(),
+        )]);
+
+        let response = block_on(
+            AsyncClient::new(server.url())
+                .post("/articles", &fixtures::article()),
+        )
+        .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Created);
+
+        let request = server.request();
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/articles");
+        assert_eq!(request.header("content-type"), Some(MIME));
+        assert_eq!(request.json(), fixtures::article_value());
+    }
+
+    #[test]
+    fn patch() {
+        let server = TestServer::start(vec![Reply::new(204)]);
+
+        let response = block_on(
+            AsyncClient::new(server.url())
+                .patch("/articles/1", &fixtures::article()),
+        )
+        .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::UpdatedWithoutContent);
+        assert_eq!(response.document(), None);
+
+        let request = server.request();
+
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(request.json(), fixtures::article_value());
+    }
+
+    #[test]
+    fn strict() {
+        let server = TestServer::start(vec![
+            Reply::document(
+                200,
+                json!({
+                    "data": [
+                        { "type": "articles", "id": "1" },
+                        { "type": "articles", "id": "1" },
+                    ],
+                }),
+            ),
+            Reply::document(200, json!({ "meta": { "foo_bar": 1 } })),
+        ]);
+        let client = AsyncClient::new(server.url()).strict(true);
+
+        match block_on(client.get("/articles", [("", ""); 0])) {
+            Err(Error::Validation(report)) => assert!(!report.is_valid()),
+            other => panic!("{:?}", other),
+        }
+
+        let response =
+            block_on(client.get("/articles", [("", ""); 0])).unwrap();
+
+        assert_eq!(
+            response.document().unwrap().meta.as_ref().unwrap()["foo_bar"],
+            1,
+        );
+    }
+
+    #[test]
+    fn without_document() {
+        let server = TestServer::start(vec![
+            Reply::new(202),
+            Reply::new(202),
+            Reply::new(201).header("Location", "/articles/1"),
+        ]);
+        let client = AsyncClient::new(server.url());
+
+        block_on(async {
+            let response = client.delete("/articles/1").await.unwrap();
+
+            assert_eq!(response.outcome(), Outcome::Accepted);
+            assert_eq!(response.document(), None);
+
+            let response = client
+                .patch("/articles/1", &fixtures::article())
+                .await
+                .unwrap();
+
+            assert_eq!(response.outcome(), Outcome::Accepted);
+            assert_eq!(response.document(), None);
+
+            let response = client
+                .post("/articles", &fixtures::article())
+                .await
+                .unwrap();
+
+            assert_eq!(response.outcome(), Outcome::Created);
+            assert_eq!(response.document(), None);
+            assert_eq!(response.location(), Some("/articles/1"));
+        });
+    }
+
+    #[test]
+    fn delete_not_found() {
+        let server = TestServer::start(vec![Reply::document(
+            404,
+            json!({ "errors": [{ "status": "404" }] }),
+        )]);
+
+        match block_on(AsyncClient::new(server.url()).delete("/articles/1")) {
+            Err(Error::Response(response)) => {
+                assert_eq!(response.outcome(), Outcome::Failed)
+            }
+            other => panic!("{:?}", other),
+        }
+
+        assert_eq!(server.request().method, "DELETE");
+    }
+
+    #[test]
+    fn error_without_document() {
+        let server = TestServer::start(vec![
+            Reply::new(404),
+            Reply::new(503)
+                .header("Content-Type", "text/html")
+                .body("<h1>Service Unavailable</h1>"),
+        ]);
+        let client = AsyncClient::new(server.url());
+
+        for status in [StatusCode::NOT_FOUND, StatusCode::SERVICE_UNAVAILABLE] {
+            match block_on(client.get("/articles/1", [("", ""); 0])) {
+                Err(Error::Response(response)) => {
+                    assert_eq!(response.status(), status);
+                    assert_eq!(response.outcome(), Outcome::Failed);
+                    assert_eq!(response.document(), None);
+                }
+                other => panic!("{:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn charset() {
+        let server = TestServer::start(vec![Reply::new(200)
+            .header("Content-Type", format!("{}; charset=iso-8859-1", MIME))
+            .bytes(b"{\"meta\":{\"title\":\"Caf\xe9\"}}".to_vec())]);
+        let client = AsyncClient::new(server.url());
+
+        let response =
+            block_on(client.get("/articles/1", [("", ""); 0])).unwrap();
+
+        assert_eq!(
+            response.document().unwrap().meta.as_ref().unwrap()["title"],
+            "Caf\u{e9}",
+        );
+    }
+
+    #[test]
+    fn relationships() {
+        let server = TestServer::start(vec![
+            Reply::document(200, fixtures::tags_value()),
+            Reply::new(204),
+        ]);
+        let client = AsyncClient::new(server.url());
+
+        let response =
+            block_on(client.get_relationship("/articles/1", "tags")).unwrap();
+
+        assert_eq!(
+            response.linkage(),
+            Some(IdentifierData::Multiple(fixtures::tags())),
+        );
+        assert_eq!(server.request().path, "/articles/1/relationships/tags");
+
+        let response = block_on(client.add_to_relationship(
+            "/articles/1",
+            "tags",
+            &fixtures::tags(),
+        ))
+        .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::UpdatedWithoutContent);
+
+        let request = server.request();
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.json(), fixtures::tags_value());
+    }
+
+    #[test]
+    fn with_http_client() {
+        let server = TestServer::start(vec![
+            Reply::new(204),
+            Reply::document(200, fixtures::article_value()),
+        ]);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("secret"));
+
+        let client = AsyncClient::with_http_client(
+            server.url(),
+            ReqClient::builder()
+                .default_headers(headers)
+                .build()
+                .unwrap(),
+        );
+
+        block_on(async {
+            client.delete("/articles/1").await.unwrap();
+            client.get("/articles/1", [("", ""); 0]).await.unwrap();
+        });
+
+        for method in ["DELETE", "GET"] {
+            let request = server.request();
+
+            assert_eq!(request.method, method);
+            assert_eq!(request.header("x-api-key"), Some("secret"));
+        }
+    }
+
+    #[test]
+    fn with_http_client_and_timeouts() {
+        let server = TestServer::start(vec![Reply::new(204)]);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("secret"));
+
+        let client = AsyncClient::with_http_client(
+            server.url(),
+            ReqClient::builder()
+                .default_headers(headers)
+                .build()
+                .unwrap(),
+        )
+        .timeouts(Timeouts {
+            connect: None,
+            read: Some(Duration::from_secs(5)),
+            total: Some(Duration::from_secs(5)),
+        })
+        .unwrap();
+
+        block_on(client.delete("/articles/1")).unwrap();
+
+        assert_eq!(server.request().header("x-api-key"), Some("secret"));
+        assert!(matches!(
+            client.timeouts(Timeouts::new().connect(Duration::from_secs(5))),
+            Err(Error::UnsupportedConnectTimeout),
+        ));
+    }
+
+    #[test]
+    fn pages() {
+        let server = TestServer::start(vec![
+            Reply::document(
+                200,
+                fixtures::page_value("1", Some(json!("/articles?page=2"))),
+            ),
+            Reply::document(200, fixtures::page_value("2", None)),
+        ]);
+        let client = AsyncClient::new(server.url());
+
+        let resources = block_on(async {
+            let response = client.get("/articles", [("", ""); 0]).await?;
+
+            client.pages(response).resources().await
+        })
+        .unwrap();
+
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources[1].id.as_deref(), Some("2"));
+        assert_eq!(server.request().path, "/articles?");
+        assert_eq!(server.request().path, "/articles?page=2");
+    }
+
+    #[test]
+    fn pages_next_to_itself() {
+        let server = TestServer::start(vec![
+            Reply::document(
+                200,
+                fixtures::page_value("1", Some(json!("/articles?page=2"))),
+            ),
+            Reply::document(
+                200,
+                fixtures::page_value("2", Some(json!("/articles?page=2"))),
+            ),
+        ]);
+        let client = AsyncClient::new(server.url());
+
+        let resources = block_on(async {
+            let response = client.get("/articles", [("", ""); 0]).await?;
+
+            client.pages(response).resources().await
+        })
+        .unwrap();
+
+        assert_eq!(resources.len(), 2);
+    }
+
+    #[test]
+    fn pages_next_to_first() {
+        let server = TestServer::start(vec![
+            Reply::document(
+                200,
+                fixtures::page_value("1", Some(json!("/articles?page=2"))),
+            ),
+            Reply::document(
+                200,
+                fixtures::page_value("2", Some(json!("/articles?page=1"))),
+            ),
+        ]);
+        let client = AsyncClient::new(server.url());
+
+        let resources = block_on(async {
+            let response = client.get("/articles", [("page", "1")]).await?;
+
+            client.pages(response).resources().await
+        })
+        .unwrap();
+
+        assert_eq!(resources.len(), 2);
+    }
+
+    #[test]
+    fn pages_with_limit() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            fixtures::page_value("1", Some(json!("/articles?page=2"))),
+        )]);
+        let client = AsyncClient::new(server.url());
+
+        let count = block_on(async {
+            let response = client.get("/articles", [("", ""); 0]).await?;
+            let mut pages = client.pages(response).limit(1);
+            let mut count = 0;
+
+            while let Some(page) = pages.next().await {
+                page?;
+                count += 1;
+            }
+
+            Ok::<_, Error>(count)
+        })
+        .unwrap();
+
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn fetch_related() {
+        let server = TestServer::start(vec![Reply::document(
+            200,
+            fixtures::article_value(),
+        )]);
+        let client = AsyncClient::new(server.url());
+
+        let response =
+            block_on(client.fetch_related(
+                &fixtures::comment_with_related_link(),
+                "article",
+            ))
+            .unwrap();
+
+        assert_eq!(response.document().unwrap(), &fixtures::article());
+        assert_eq!(server.request().path, "/comments/5/article");
+    }
+
+    #[test]
+    fn auth_refresh() {
+        let server = TestServer::start(vec![
+            Reply::new(401),
+            Reply::document(200, fixtures::article_value()),
+        ]);
+        let client =
+            AsyncClient::new(server.url()).auth(Auth::provider(|refresh| {
+                let mut headers = HeaderMap::new();
+                headers.insert(
+                    "x-api-key",
+                    HeaderValue::from_static(if refresh {
+                        "new"
+                    } else {
+                        "old"
+                    }),
+                );
+                headers
+            }));
+
+        let response =
+            block_on(client.get("/articles/1", [("", ""); 0])).unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Fetched);
+        assert_eq!(server.request().header("x-api-key"), Some("old"));
+        assert_eq!(server.request().header("x-api-key"), Some("new"));
+    }
+
+    struct RequestId;
+
+    impl Middleware for RequestId {
+        fn request(&self, request: &mut Request) {
+            request
+                .headers
+                .insert("x-request-id", HeaderValue::from_static("42"));
+        }
+    }
+
+    #[test]
+    fn middleware() {
+        let server = TestServer::start(vec![Reply::new(204)]);
+
+        block_on(
+            AsyncClient::new(server.url())
+                .middleware(RequestId)
+                .delete("/articles/1"),
+        )
+        .unwrap();
+
+        assert_eq!(server.request().header("x-request-id"), Some("42"));
+    }
+
+    #[test]
+    fn retry() {
+        let server = TestServer::start(vec![
+            Reply::new(429).header("Retry-After", "0"),
+            Reply::document(200, fixtures::article_value()),
+        ]);
+
+        let response = block_on(
+            AsyncClient::new(server.url())
+                .retry(RetryPolicy::new())
+                .get("/articles/1", [("", ""); 0]),
+        )
+        .unwrap();
+
+        assert_eq!(response.outcome(), Outcome::Fetched);
+        assert_eq!(server.request().method, "GET");
+        assert_eq!(server.request().method, "GET");
+    }
+
+    #[test]
+    fn retry_gives_up() {
+        let server = TestServer::start(vec![Reply::new(503), Reply::new(503)]);
+
+        let result = block_on(
+            AsyncClient::new(server.url())
+                .retry(RetryPolicy::new().max_attempts(2).backoff(
+                    Duration::from_millis(1),
+                    Duration::from_millis(1),
+                ))
+                .delete("/articles/1"),
+        );
+
+        match result {
+            Err(Error::Response(response)) => {
+                assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+                assert_eq!(response.outcome(), Outcome::Failed);
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn timeouts() {
+        let server = TestServer::start(vec![
+            Reply::new(204).delay(Duration::from_millis(500)),
+            Reply::new(204).delay(Duration::from_millis(500)),
+            Reply::new(204).delay(Duration::from_millis(200)),
+        ]);
+        let client = AsyncClient::new(server.url())
+            .timeouts(Timeouts::new().read(Duration::from_millis(50)))
+            .unwrap();
+
+        block_on(async {
+            assert!(matches!(
+                client.delete("/articles/1").await,
+                Err(Error::Timeout),
+            ));
+
+            let client = client
+                .with_timeouts(Timeouts::new().total(Duration::from_millis(50)))
+                .unwrap();
+
+            assert!(matches!(
+                client.delete("/articles/1").await,
+                Err(Error::Timeout),
+            ));
+
+            let response = client
+                .with_timeouts(
+                    Timeouts::new()
+                        .read(Duration::from_secs(5))
+                        .total(Duration::from_secs(5)),
+                )
+                .unwrap()
+                .delete("/articles/1")
+                .await
+                .unwrap();
+
+            assert_eq!(response.outcome(), Outcome::DeletedWithoutContent);
+        });
+    }
+
+    #[test]
+    fn connect_timeout() {
+        let server = UnresponsiveServer::start();
+        let client = AsyncClient::new(server.url())
+            .timeouts(Timeouts::new().connect(Duration::from_millis(100)))
+            .unwrap();
+
+        assert!(matches!(
+            block_on(client.delete("/articles/1")),
+            Err(Error::Timeout),
+        ));
+    }
+
+    #[test]
+    fn total_timeout_with_retries() {
+        let server = TestServer::start(vec![
+            Reply::new(503).delay(Duration::from_millis(150)),
+            Reply::new(503).delay(Duration::from_millis(150)),
+            Reply::new(204),
+        ]);
+        let client =
+            AsyncClient::new(server.url())
+                .retry(RetryPolicy::new().backoff(
+                    Duration::from_millis(1),
+                    Duration::from_millis(1),
+                ))
+                .timeouts(Timeouts::new().total(Duration::from_millis(250)))
+                .unwrap();
+
+        assert!(matches!(
+            block_on(client.delete("/articles/1")),
+            Err(Error::Timeout),
+        ));
+    }
+
+    #[test]
+    fn response_accessors() {
+        let server = TestServer::start(vec![Reply::document(
+            201,
+            fixtures::article_value(),
+        )
+        .header("Location", "/articles/1")]);
+
+        let response = block_on(
+            AsyncClient::new(server.url())
+                .post("/articles", &fixtures::article()),
+        )
+        .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.location(), Some("/articles/1"));
+        assert_eq!(response.headers()["content-type"], MIME);
+        assert_eq!(
+            response.body(),
+            Some(fixtures::article_value().to_string().as_bytes()),
+        );
+    }
+}
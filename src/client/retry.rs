@@ -0,0 +1,275 @@
+use super::*;
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::RETRY_AFTER;
+
+// Retries requests which the server answers with 429 Too Many Requests or
+// 503 Service Unavailable. Only idempotent methods are retried by default,
+// because the server may have processed a request despite the error.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    all_methods: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            all_methods: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Including the first attempt.
+    pub fn max_attempts(self, max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..self
+        }
+    }
+
+    // The backoff is doubled after every attempt up to the maximum. A random
+    // part of up to a half of it is subtracted, so clients which failed at
+    // the same time don't retry at the same time. A request isn't retried
+    // when the server asks with the "Retry-After" header to wait longer
+    // than the maximum.
+    pub fn backoff(self, initial: Duration, max: Duration) -> Self {
+        Self {
+            initial_backoff: initial,
+            max_backoff: max,
+            ..self
+        }
+    }
+
+    // Also retry POST and PATCH requests.
+    pub fn all_methods(self, all_methods: bool) -> Self {
+        Self {
+            all_methods,
+            ..self
+        }
+    }
+
+    // Delay before the next attempt, if the request should be retried after
+    // the given attempt (counting from 1).
+    pub(super) fn delay(
+        &self,
+        method: &Method,
+        status: StatusCode,
+        headers: &HeaderMap,
+        attempt: u32,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts
+            || !(self.all_methods || is_idempotent(method))
+            || !(status == StatusCode::TOO_MANY_REQUESTS
+                || status == StatusCode::SERVICE_UNAVAILABLE)
+        {
+            return None;
+        }
+
+        match retry_after(headers) {
+            None => Some(self.backoff_for(attempt)),
+            Some(delay) if delay > self.max_backoff => None,
+            Some(delay) => Some(delay),
+        }
+    }
+
+    fn backoff_for(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .checked_mul(2u32.saturating_pow(attempt - 1))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff));
+
+        let jitter = (RandomState::new().build_hasher().finish() % 1000) as f64;
+
+        backoff - (backoff / 2).mul_f64(jitter / 1000.0)
+    }
+}
+
+// https://www.rfc-editor.org/rfc/rfc9110#name-idempotent-methods
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET
+            | Method::HEAD
+            | Method::PUT
+            | Method::DELETE
+            | Method::OPTIONS
+            | Method::TRACE
+    )
+}
+
+// https://www.rfc-editor.org/rfc/rfc9110#name-retry-after
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    match value.parse() {
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => {
+            let date = httpdate::parse_http_date(value).ok()?;
+
+            Some(
+                date.duration_since(SystemTime::now())
+                    .unwrap_or(Duration::ZERO),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(retry_after: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, retry_after.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn statuses() {
+        let policy = RetryPolicy::new();
+        let delay =
+            |status| policy.delay(&Method::GET, status, &headers("1"), 1);
+
+        assert_eq!(
+            delay(StatusCode::TOO_MANY_REQUESTS),
+            Some(Duration::from_secs(1)),
+        );
+        assert_eq!(
+            delay(StatusCode::SERVICE_UNAVAILABLE),
+            Some(Duration::from_secs(1)),
+        );
+        assert_eq!(delay(StatusCode::INTERNAL_SERVER_ERROR), None);
+        assert_eq!(delay(StatusCode::OK), None);
+    }
+
+    #[test]
+    fn retry_after_limit() {
+        let policy = RetryPolicy::new()
+            .backoff(Duration::from_millis(100), Duration::from_secs(30));
+        let delay = |retry_after| {
+            policy.delay(
+                &Method::GET,
+                StatusCode::SERVICE_UNAVAILABLE,
+                &headers(retry_after),
+                1,
+            )
+        };
+
+        assert_eq!(delay("20"), Some(Duration::from_secs(20)));
+        assert_eq!(delay("30"), Some(Duration::from_secs(30)));
+        assert_eq!(delay("60"), None);
+        assert_eq!(delay("86400"), None);
+        assert_eq!(
+            delay(&httpdate::fmt_http_date(
+                SystemTime::now() + Duration::from_secs(86400),
+            )),
+            None,
+        );
+    }
+
+    #[test]
+    fn max_attempts() {
+        let policy = RetryPolicy::new().max_attempts(2);
+        let delay = |attempt| {
+            policy.delay(
+                &Method::GET,
+                StatusCode::SERVICE_UNAVAILABLE,
+                &HeaderMap::new(),
+                attempt,
+            )
+        };
+
+        assert!(delay(1).is_some());
+        assert_eq!(delay(2), None);
+        assert_eq!(delay(3), None);
+    }
+
+    #[test]
+    fn methods() {
+        let delay = |policy: &RetryPolicy, method| {
+            policy.delay(
+                &method,
+                StatusCode::SERVICE_UNAVAILABLE,
+                &HeaderMap::new(),
+                1,
+            )
+        };
+
+        let policy = RetryPolicy::new();
+
+        for method in [Method::GET, Method::PUT, Method::DELETE] {
+            assert!(delay(&policy, method).is_some());
+        }
+        for method in [Method::POST, Method::PATCH] {
+            assert_eq!(delay(&policy, method), None);
+        }
+
+        let policy = RetryPolicy::new().all_methods(true);
+
+        for method in [Method::POST, Method::PATCH] {
+            assert!(delay(&policy, method).is_some());
+        }
+    }
+
+    #[test]
+    fn backoff() {
+        let policy = RetryPolicy::new()
+            .max_attempts(10)
+            .backoff(Duration::from_millis(100), Duration::from_millis(500));
+
+        for (attempt, max) in [(1, 100), (2, 200), (3, 400), (4, 500), (9, 500)]
+        {
+            let backoff = policy.backoff_for(attempt);
+
+            assert!(backoff <= Duration::from_millis(max));
+            assert!(backoff >= Duration::from_millis(max / 2));
+        }
+    }
+
+    #[test]
+    fn backoff_overflow() {
+        let policy = RetryPolicy::new()
+            .backoff(Duration::from_secs(1), Duration::from_secs(60));
+
+        assert!(policy.backoff_for(100) <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        assert_eq!(
+            retry_after(&headers("120")),
+            Some(Duration::from_secs(120)),
+        );
+        assert_eq!(retry_after(&headers("soon")), None);
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn retry_after_date() {
+        let date = SystemTime::now() + Duration::from_secs(60);
+        let delay =
+            retry_after(&headers(&httpdate::fmt_http_date(date))).unwrap();
+
+        assert!(delay <= Duration::from_secs(60));
+        assert!(delay >= Duration::from_secs(58));
+
+        assert_eq!(
+            retry_after(&headers("Wed, 21 Oct 2015 07:28:00 GMT")),
+            Some(Duration::ZERO),
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use super::*;
+
+use std::time::Instant;
+
+// Timeouts of requests. Unset timeouts are not limited, except by the HTTP
+// client.
+//
+// The connect timeout is a setting of the HTTP client, so it can only be set
+// as a default of a client with the default HTTP client. The read timeout limits waiting for the response and for
+// its body. The total timeout limits the whole request, including retries.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Timeouts {
+    pub connect: Option<Duration>,
+    pub read: Option<Duration>,
+    pub total: Option<Duration>,
+}
+
+impl Timeouts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn connect(self, connect: Duration) -> Self {
+        Self {
+            connect: Some(connect),
+            ..self
+        }
+    }
+
+    pub fn read(self, read: Duration) -> Self {
+        Self {
+            read: Some(read),
+            ..self
+        }
+    }
+
+    pub fn total(self, total: Duration) -> Self {
+        Self {
+            total: Some(total),
+            ..self
+        }
+    }
+
+    // Timeouts which are set in the overrides replace these ones.
+    pub(super) fn merge(self, overrides: Self) -> Self {
+        Self {
+            connect: overrides.connect.or(self.connect),
+            read: overrides.read.or(self.read),
+            total: overrides.total.or(self.total),
+        }
+    }
+
+    // The rest of the total timeout of a request which started at the given
+    // instant.
+    pub(super) fn total_left(&self, start: Instant) -> Option<Duration> {
+        self.total
+            .map(|total| total.saturating_sub(start.elapsed()))
+    }
+
+    // The shortest one of the read timeout and the rest of the total one.
+    #[cfg(feature = "client")]
+    pub(super) fn shortest(&self, start: Instant) -> Option<Duration> {
+        match (self.read, self.total_left(start)) {
+            (Some(read), Some(total)) => Some(read.min(total)),
+            (read, total) => read.or(total),
+        }
+    }
+
+    // Fails when the total timeout would be exceeded after the given delay.
+    pub(super) fn check_total(
+        &self,
+        start: Instant,
+        delay: Duration,
+    ) -> std::result::Result<(), Error> {
+        match self.total {
+            Some(total) if start.elapsed() + delay > total => {
+                Err(Error::Timeout)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Error {
+    pub(super) fn from_http(error: ReqError) -> Self {
+        if error.is_timeout() {
+            Self::Timeout
+        } else {
+            Self::Http(error)
+        }
+    }
+
+    pub(super) fn from_text(error: ReqError) -> Self {
+        if error.is_timeout() {
+            Self::Timeout
+        } else {
+            Self::Text(error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn merge() {
+        let defaults = Timeouts::new().connect(secs(1)).read(secs(2));
+        let timeouts =
+            defaults.merge(Timeouts::new().read(secs(3)).total(secs(4)));
+
+        assert_eq!(timeouts.connect, Some(secs(1)));
+        assert_eq!(timeouts.read, Some(secs(3)));
+        assert_eq!(timeouts.total, Some(secs(4)));
+        assert_eq!(defaults.merge(Timeouts::new()), defaults);
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn shortest() {
+        let start = Instant::now();
+
+        assert_eq!(Timeouts::new().shortest(start), None);
+        assert_eq!(Timeouts::new().connect(secs(1)).shortest(start), None);
+        assert_eq!(
+            Timeouts::new().read(secs(2)).shortest(start),
+            Some(secs(2)),
+        );
+        assert!(Timeouts::new()
+            .read(secs(5))
+            .total(secs(4))
+            .shortest(start)
+            .is_some_and(|timeout| timeout <= secs(4)));
+        assert_eq!(
+            Timeouts::new()
+                .read(secs(5))
+                .total(secs(1))
+                .shortest(start - secs(2)),
+            Some(Duration::ZERO),
+        );
+    }
+
+    #[test]
+    fn check_total() {
+        let start = Instant::now() - secs(2);
+
+        assert!(Timeouts::new().check_total(start, secs(60)).is_ok());
+        assert!(Timeouts::new()
+            .total(secs(60))
+            .check_total(start, Duration::ZERO)
+            .is_ok());
+        assert!(matches!(
+            Timeouts::new()
+                .total(secs(1))
+                .check_total(start, Duration::ZERO),
+            Err(Error::Timeout),
+        ));
+        assert!(matches!(
+            Timeouts::new().total(secs(60)).check_total(start, secs(60)),
+            Err(Error::Timeout),
+        ));
+    }
+}
@@ -2,8 +2,12 @@ use super::*;
 
 impl Entity<'_> for Data {}
 
+// Boxing the single resource would be a breaking change, and newer Clippy
+// warns about the size difference.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Data {
+    Null,
     Single(Resource),
     Multiple(Vec<Resource>),
 }
@@ -14,6 +18,7 @@ impl Serialize for Data {
         S: Serializer,
     {
         match self {
+            Self::Null => serializer.serialize_none(),
             Self::Single(single) => single.serialize(serializer),
             Self::Multiple(multiple) => multiple.serialize(serializer),
         }
@@ -34,6 +39,20 @@ impl<'de> Deserialize<'de> for Data {
                 formatter.write_str("JSON API data")
             }
 
+            fn visit_none<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(Data::Null)
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(Data::Null)
+            }
+
             fn visit_map<A>(self, value: A) -> Result<Self::Value, A::Error>
             where
                 A: serde::de::MapAccess<'de>,
@@ -63,6 +82,8 @@ mod tests {
 
     #[test]
     fn debug() {
+        assert_eq!(format!("{:?}", Data::Null), "Null");
+
         assert_eq!(format!("{:?}", Data::Multiple(vec![])), "Multiple([])",);
 
         assert_eq!(
@@ -71,6 +92,7 @@ mod tests {
                 Data::Single(Resource {
                     type_: "qwerties".into(),
                     id: None,
+                    lid: None,
                     meta: None,
                     links: None,
                     attributes: None,
@@ -80,6 +102,7 @@ mod tests {
             "Single(Resource { \
                 type_: \"qwerties\", \
                 id: None, \
+                lid: None, \
                 meta: None, \
                 links: None, \
                 attributes: None, \
@@ -93,6 +116,7 @@ mod tests {
                 Data::Multiple(vec![Resource {
                     type_: "qwerties".into(),
                     id: None,
+                    lid: None,
                     meta: None,
                     links: None,
                     attributes: None,
@@ -102,6 +126,7 @@ mod tests {
             "Multiple([Resource { \
                 type_: \"qwerties\", \
                 id: None, \
+                lid: None, \
                 meta: None, \
                 links: None, \
                 attributes: None, \
@@ -112,12 +137,15 @@ mod tests {
 
     #[test]
     fn equality() {
+        assert_eq!(Data::Null, Data::Null);
         assert_eq!(Data::Multiple(vec![]), Data::Multiple(vec![]));
+        assert_ne!(Data::Null, Data::Multiple(vec![]));
 
         assert_eq!(
             Data::Single(Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
@@ -126,6 +154,7 @@ mod tests {
             Data::Single(Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
@@ -137,6 +166,7 @@ mod tests {
             Data::Multiple(vec![Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
@@ -145,6 +175,7 @@ mod tests {
             Data::Multiple(vec![Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
@@ -4,11 +4,22 @@ impl Entity<'_> for Document {}
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Document {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jsonapi: Option<JsonApi>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<MetaOrAttrs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<Links>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_present",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub data: Option<Data>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub errors: Option<Errors>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub included: Option<Vec<Resource>>,
 }
 
 #[cfg(test)]
@@ -26,6 +37,7 @@ mod tests {
                     links: None,
                     data: None,
                     errors: None,
+                    included: None,
                 },
             ),
             "Document { \
@@ -33,7 +45,8 @@ mod tests {
                 meta: None, \
                 links: None, \
                 data: None, \
-                errors: None \
+                errors: None, \
+                included: None \
             }",
         );
     }
@@ -47,6 +60,7 @@ mod tests {
                 links: None,
                 data: None,
                 errors: None,
+                included: None,
             },
             Document {
                 jsonapi: None,
@@ -54,6 +68,7 @@ mod tests {
                 links: None,
                 data: None,
                 errors: None,
+                included: None,
             },
         );
 
@@ -64,6 +79,7 @@ mod tests {
                 links: None,
                 data: None,
                 errors: None,
+                included: None,
             },
             Document {
                 jsonapi: Some(JsonApi {
@@ -74,6 +90,7 @@ mod tests {
                 links: None,
                 data: None,
                 errors: None,
+                included: None,
             },
         );
     }
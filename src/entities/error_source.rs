@@ -5,6 +5,8 @@ impl Entity<'_> for ErrorSource {}
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ErrorSource {
     // TODO: Add entity with validation
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pointer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parameter: Option<String>,
 }
@@ -0,0 +1,148 @@
+use super::*;
+
+impl Entity<'_> for IdentifierData {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IdentifierData {
+    Null,
+    Single(ResourceIdentifier),
+    Multiple(Vec<ResourceIdentifier>),
+}
+
+impl Serialize for IdentifierData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Null => serializer.serialize_none(),
+            Self::Single(single) => single.serialize(serializer),
+            Self::Multiple(multiple) => multiple.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for IdentifierData {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct MyVisitor;
+
+        impl<'de> Visitor<'de> for MyVisitor {
+            type Value = IdentifierData;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("JSON API resource linkage")
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(IdentifierData::Null)
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(IdentifierData::Null)
+            }
+
+            fn visit_map<A>(self, value: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                Ok(IdentifierData::Single(Deserialize::deserialize(
+                    serde::de::value::MapAccessDeserializer::new(value),
+                )?))
+            }
+
+            fn visit_seq<A>(self, value: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                Ok(IdentifierData::Multiple(Deserialize::deserialize(
+                    serde::de::value::SeqAccessDeserializer::new(value),
+                )?))
+            }
+        }
+
+        deserializer.deserialize_any(MyVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", IdentifierData::Null), "Null");
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                IdentifierData::Single(ResourceIdentifier {
+                    type_: "qwerties".into(),
+                    id: Some("123".into()),
+                    lid: None,
+                    meta: None,
+                }),
+            ),
+            "Single(ResourceIdentifier { \
+                type_: \"qwerties\", \
+                id: Some(\"123\"), \
+                lid: None, \
+                meta: None \
+            })",
+        );
+    }
+
+    #[test]
+    fn serialize_and_deserialize() {
+        for identifier_data in [
+            IdentifierData::Null,
+            IdentifierData::Single(ResourceIdentifier {
+                type_: "qwerties".into(),
+                id: Some("123".into()),
+                lid: None,
+                meta: None,
+            }),
+            IdentifierData::Multiple(vec![]),
+            IdentifierData::Multiple(vec![ResourceIdentifier {
+                type_: "qwerties".into(),
+                id: None,
+                lid: Some("local-1".into()),
+                meta: None,
+            }]),
+        ] {
+            let json = serde_json::to_string(&identifier_data).unwrap();
+
+            let deserialized: IdentifierData =
+                serde_json::from_str(&json).unwrap();
+
+            assert_eq!(identifier_data, deserialized);
+        }
+    }
+
+    #[test]
+    fn deserialize_rejects_full_resources() {
+        let value = json!({
+            "type": json!("qwerties"),
+            "id": json!("123"),
+            "attributes": json!({ "foo": json!(123) }),
+        });
+
+        assert!(
+            serde_json::from_value::<IdentifierData>(value.clone()).is_err()
+        );
+
+        assert!(
+            serde_json::from_value::<IdentifierData>(json!([value])).is_err()
+        );
+    }
+}
@@ -4,7 +4,9 @@ impl Entity<'_> for JsonApi {}
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct JsonApi {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<Version>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<MetaOrAttrs>,
 }
 
@@ -8,6 +8,15 @@ pub enum Link {
     Object(LinkObject),
 }
 
+impl Link {
+    pub fn href(&self) -> &str {
+        match self {
+            Self::String(href) => href,
+            Self::Object(object) => &object.href,
+        }
+    }
+}
+
 impl Serialize for Link {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5,5 +5,6 @@ impl Entity<'_> for LinkObject {}
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct LinkObject {
     pub href: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<MetaOrAttrs>,
 }
@@ -4,7 +4,7 @@ impl Entity<'_> for Links {}
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Links {
-    pub other: HashMap<String, Link>,
+    pub other: Map<String, Link>,
     // Basic (https://jsonapi.org/format/#document-links)
     pub self_: Option<Link>,
     pub related: Option<Link>,
@@ -22,27 +22,36 @@ impl Serialize for Links {
     where
         S: Serializer,
     {
-        let mut all: HashMap<String, Option<Link>> = HashMap::new();
-
-        for (key, value) in &self.other {
-            all.insert(key.into(), Some(value.clone()));
-        }
-
-        // Basic
-        all.insert("self".into(), self.self_.clone());
-        all.insert("related".into(), self.related.clone());
-        // Pagination
-        all.insert("first".into(), self.first.clone());
-        all.insert("last".into(), self.last.clone());
-        all.insert("prev".into(), self.prev.clone());
-        all.insert("next".into(), self.next.clone());
-        // Errors
-        all.insert("about".into(), self.about.clone());
-
-        let mut map = serializer.serialize_map(Some(all.len()))?;
-
-        for (key, value) in all {
-            map.serialize_entry(&key, &value)?;
+        let standard = [
+            // Basic
+            ("self", &self.self_),
+            ("related", &self.related),
+            // Pagination
+            ("first", &self.first),
+            ("last", &self.last),
+            ("prev", &self.prev),
+            ("next", &self.next),
+            // Errors
+            ("about", &self.about),
+        ];
+
+        let standard: Vec<(&str, &Link)> = standard
+            .into_iter()
+            .filter_map(|(key, value)| value.as_ref().map(|value| (key, value)))
+            .collect();
+
+        let other: Vec<(&str, &Link)> = self
+            .other
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+            .filter(|(key, _)| !standard.iter().any(|(other, _)| key == other))
+            .collect();
+
+        let mut map =
+            serializer.serialize_map(Some(standard.len() + other.len()))?;
+
+        for (key, value) in standard.into_iter().chain(other) {
+            map.serialize_entry(key, value)?;
         }
 
         map.end()
@@ -72,41 +81,40 @@ impl<'de> Deserialize<'de> for Links {
                 ) {
                     Err(err) => Err(err),
                     Ok(all) => {
-                        let mut all: HashMap<String, Option<Link>> = all;
-
-                        // Basic
-                        let self_: Option<Option<Link>> = all.remove("self");
-                        let related: Option<Option<Link>> =
-                            all.remove("related");
-                        // Pagination
-                        let first: Option<Option<Link>> = all.remove("first");
-                        let last: Option<Option<Link>> = all.remove("last");
-                        let prev: Option<Option<Link>> = all.remove("prev");
-                        let next: Option<Option<Link>> = all.remove("next");
-                        // Errors
-                        let about: Option<Option<Link>> = all.remove("about");
-
-                        let mut other: HashMap<String, Link> = HashMap::new();
+                        let all: Map<String, Option<Link>> = all;
+
+                        let mut links = Links {
+                            other: Map::new(),
+                            self_: None,
+                            related: None,
+                            first: None,
+                            last: None,
+                            prev: None,
+                            next: None,
+                            about: None,
+                        };
 
                         for (key, value) in all {
-                            if let Some(value) = value {
-                                other.insert(key, value);
+                            match key.as_str() {
+                                // Basic
+                                "self" => links.self_ = value,
+                                "related" => links.related = value,
+                                // Pagination
+                                "first" => links.first = value,
+                                "last" => links.last = value,
+                                "prev" => links.prev = value,
+                                "next" => links.next = value,
+                                // Errors
+                                "about" => links.about = value,
+                                _ => {
+                                    if let Some(value) = value {
+                                        links.other.insert(key, value);
+                                    }
+                                }
                             }
                         }
 
-                        Ok(Self::Value {
-                            other,
-                            // Basic
-                            self_: self_.unwrap_or(None),
-                            related: related.unwrap_or(None),
-                            // Pagination
-                            first: first.unwrap_or(None),
-                            last: last.unwrap_or(None),
-                            prev: prev.unwrap_or(None),
-                            next: next.unwrap_or(None),
-                            // Errors
-                            about: about.unwrap_or(None),
-                        })
+                        Ok(links)
                     }
                 }
             }
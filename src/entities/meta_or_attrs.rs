@@ -2,4 +2,4 @@ use super::*;
 
 impl Entity<'_> for MetaOrAttrs {}
 
-pub type MetaOrAttrs = HashMap<String, Value>;
+pub type MetaOrAttrs = Map<String, Value>;
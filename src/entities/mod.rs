@@ -4,14 +4,17 @@ mod error_object;
 mod error_source;
 mod errors;
 mod http_status;
+mod identifier_data;
 mod jsonapi;
 mod link;
 mod link_object;
 mod links;
 mod meta_or_attrs;
+mod query;
 mod relationship;
 mod relationships;
 mod resource;
+mod resource_identifier;
 mod version;
 
 pub use data::Data;
@@ -20,18 +23,20 @@ pub use error_object::ErrorObject;
 pub use error_source::ErrorSource;
 pub use errors::Errors;
 pub use http_status::HttpStatus;
+pub use identifier_data::IdentifierData;
 pub use jsonapi::JsonApi;
 pub use link::Link;
 pub use link_object::LinkObject;
 pub use links::Links;
 pub use meta_or_attrs::MetaOrAttrs;
+pub use query::{Query, SortDirection, SortField};
 pub use relationship::Relationship;
 pub use relationships::Relationships;
 pub use resource::Resource;
+pub use resource_identifier::ResourceIdentifier;
 pub use version::Version;
 
 use std::cmp::Ordering;
-use std::collections::HashMap;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::str::FromStr;
 
@@ -42,11 +47,29 @@ use serde::{
 };
 use serde_json::Value;
 
+// Keeps members in the order in which they were inserted or deserialized, so
+// the serialized output is reproducible. The "preserve_order" feature does
+// the same for objects inside of member values.
+pub type Map<K, V> = indexmap::IndexMap<K, V>;
+
 pub trait Entity<'de>:
     Clone + Debug + Deserialize<'de> + Eq + PartialEq + Serialize + Sized
 {
 }
 
+// Unlike the default implementation for `Option`, passes explicit `null` to
+// the inner type (see `Data::Null`). Use together with `#[serde(default)]` so
+// that absent member is still deserialized as `None`.
+fn deserialize_present<'de, D, T>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,7 +84,7 @@ mod tests {
             Relationship {
                 meta: None,
                 links: None,
-                data: None,
+                data: Some(IdentifierData::Null),
             },
         );
         expected_relationships.insert(
@@ -69,13 +92,11 @@ mod tests {
             Relationship {
                 meta: Some(fixtures::meta_or_attrs()),
                 links: Some(fixtures::different_links()),
-                data: Some(Data::Single(Resource {
+                data: Some(IdentifierData::Single(ResourceIdentifier {
                     type_: "qwerties".into(),
                     id: Some("123".into()),
+                    lid: None,
                     meta: Some(fixtures::meta_or_attrs()),
-                    links: Some(fixtures::different_links()),
-                    attributes: Some(fixtures::meta_or_attrs()),
-                    relationships: None,
                 })),
             },
         );
@@ -85,8 +106,6 @@ mod tests {
     fn expected_relationships_value() -> Value {
         json!({
             "car": json!({
-                "meta": json!(null),
-                "links": json!(null),
                 "data": json!(null),
             }),
             "cdr": json!({
@@ -96,9 +115,6 @@ mod tests {
                     "type": json!("qwerties"),
                     "id": json!("123"),
                     "meta": fixtures::meta_or_attrs_value(),
-                    "links": fixtures::different_links_value(),
-                    "attributes": fixtures::meta_or_attrs_value(),
-                    "relationships": json!(null),
                 }),
             }),
         })
@@ -116,12 +132,22 @@ mod tests {
             data: Some(Data::Multiple(vec![Resource {
                 type_: "qwerties".into(),
                 id: Some("123".into()),
+                lid: None,
                 meta: Some(fixtures::meta_or_attrs()),
                 links: Some(fixtures::different_links()),
                 attributes: Some(fixtures::meta_or_attrs()),
                 relationships: Some(expected_relationships()),
             }])),
             errors: Some(fixtures::full_errors()),
+            included: Some(vec![Resource {
+                type_: "qwerties".into(),
+                id: Some("456".into()),
+                lid: None,
+                meta: None,
+                links: None,
+                attributes: Some(fixtures::meta_or_attrs()),
+                relationships: None,
+            }]),
         };
 
         let serialized = serde_json::to_string(&document).unwrap();
@@ -145,6 +171,7 @@ mod tests {
                 links: Some(fixtures::different_links()),
                 data: None,
                 errors: None,
+                included: None,
             };
 
             let value = json!({
@@ -169,8 +196,9 @@ mod tests {
                 jsonapi: None,
                 meta: None,
                 links: None,
-                data: None,
+                data: Some(Data::Null),
                 errors: None,
+                included: None,
             };
 
             let json = "{\"data\": null}";
@@ -188,6 +216,7 @@ mod tests {
                 links: None,
                 data: Some(Data::Multiple(vec![])),
                 errors: None,
+                included: None,
             };
 
             let json = "{\"data\": []}";
@@ -206,18 +235,21 @@ mod tests {
                 data: Some(Data::Single(Resource {
                     type_: "qwerties".into(),
                     id: Some("123".into()),
+                    lid: None,
                     meta: Some(fixtures::meta_or_attrs()),
                     links: Some(fixtures::different_links()),
                     attributes: Some(fixtures::meta_or_attrs()),
                     relationships: Some(expected_relationships()),
                 })),
                 errors: None,
+                included: None,
             };
 
             let value = json!({
                 "data": json!({
                     "type": json!("qwerties"),
                     "id": json!("123"),
+                    "lid": json!(null),
                     "meta": fixtures::meta_or_attrs_value(),
                     "links": fixtures::different_links_value(),
                     "attributes": fixtures::meta_or_attrs_value(),
@@ -241,12 +273,14 @@ mod tests {
                 data: Some(Data::Multiple(vec![Resource {
                     type_: "qwerties".into(),
                     id: Some("123".into()),
+                    lid: None,
                     meta: Some(fixtures::meta_or_attrs()),
                     links: Some(fixtures::different_links()),
                     attributes: Some(fixtures::meta_or_attrs()),
                     relationships: Some(expected_relationships()),
                 }])),
                 errors: None,
+                included: None,
             };
 
             let value = json!({
@@ -254,6 +288,7 @@ mod tests {
                     json!({
                         "type": json!("qwerties"),
                         "id": json!("123"),
+                        "lid": json!(null),
                         "meta": fixtures::meta_or_attrs_value(),
                         "links": fixtures::different_links_value(),
                         "attributes": fixtures::meta_or_attrs_value(),
@@ -268,6 +303,77 @@ mod tests {
 
             assert_eq!(document, expected_document);
         }
+
+        #[test]
+        fn relationship_data_as_full_resource() {
+            let value = json!({
+                "data": json!({
+                    "type": json!("qwerties"),
+                    "id": json!("123"),
+                    "relationships": json!({
+                        "foo": json!({
+                            "data": json!({
+                                "type": json!("qwerties"),
+                                "id": json!("456"),
+                                "attributes": fixtures::meta_or_attrs_value(),
+                            }),
+                        }),
+                    }),
+                }),
+            });
+
+            let json = serde_json::to_string(&value).unwrap();
+
+            assert!(serde_json::from_str::<Document>(&json).is_err());
+        }
+
+        #[test]
+        fn included() {
+            let expected_document = Document {
+                jsonapi: None,
+                meta: None,
+                links: None,
+                data: Some(Data::Single(Resource {
+                    type_: "qwerties".into(),
+                    id: Some("123".into()),
+                    lid: None,
+                    meta: None,
+                    links: None,
+                    attributes: None,
+                    relationships: None,
+                })),
+                errors: None,
+                included: Some(vec![Resource {
+                    type_: "qwerties".into(),
+                    id: Some("456".into()),
+                    lid: None,
+                    meta: None,
+                    links: None,
+                    attributes: Some(fixtures::meta_or_attrs()),
+                    relationships: None,
+                }]),
+            };
+
+            let value = json!({
+                "data": json!({
+                    "type": json!("qwerties"),
+                    "id": json!("123"),
+                }),
+                "included": json!([
+                    json!({
+                        "type": json!("qwerties"),
+                        "id": json!("456"),
+                        "attributes": fixtures::meta_or_attrs_value(),
+                    }),
+                ]),
+            });
+
+            let json = serde_json::to_string(&value).unwrap();
+
+            let document: Document = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(document, expected_document);
+        }
     }
 
     mod serialize {
@@ -281,22 +387,14 @@ mod tests {
                 links: None,
                 data: None,
                 errors: None,
+                included: None,
             };
 
             let json = serde_json::to_string(&document).unwrap();
 
             let value: Value = serde_json::from_str(&json).unwrap();
 
-            assert_eq!(
-                value,
-                json!({
-                    "jsonapi": json!(null),
-                    "meta": json!(null),
-                    "links": json!(null),
-                    "data": json!(null),
-                    "errors": json!(null),
-                })
-            );
+            assert_eq!(value, json!({}));
         }
 
         #[test]
@@ -311,12 +409,22 @@ mod tests {
                 data: Some(Data::Multiple(vec![Resource {
                     type_: "qwerties".into(),
                     id: Some("123".into()),
+                    lid: None,
                     meta: Some(fixtures::meta_or_attrs()),
                     links: Some(fixtures::different_links()),
                     attributes: Some(fixtures::meta_or_attrs()),
                     relationships: Some(expected_relationships()),
                 }])),
                 errors: Some(fixtures::full_errors()),
+                included: Some(vec![Resource {
+                    type_: "qwerties".into(),
+                    id: Some("456".into()),
+                    lid: None,
+                    meta: None,
+                    links: None,
+                    attributes: Some(fixtures::meta_or_attrs()),
+                    relationships: None,
+                }]),
             };
 
             let json = serde_json::to_string(&document).unwrap();
@@ -343,14 +451,80 @@ mod tests {
                         }),
                     ]),
                     "errors": fixtures::full_errors_value(),
+                    "included": json!([
+                        json!({
+                            "type": json!("qwerties"),
+                            "id": json!("456"),
+                            "attributes": fixtures::meta_or_attrs_value(),
+                        }),
+                    ]),
                 })
             );
         }
 
+        #[test]
+        fn data_as_null() {
+            let document = Document {
+                jsonapi: None,
+                meta: None,
+                links: None,
+                data: Some(Data::Null),
+                errors: None,
+                included: None,
+            };
+
+            let json = serde_json::to_string(&document).unwrap();
+
+            let value: Value = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(value["data"], json!(null));
+            assert_eq!(
+                serde_json::from_value::<Document>(value).unwrap(),
+                document,
+            );
+        }
+
+        #[test]
+        fn relationship_data_absent_and_null() {
+            for (data, value) in [
+                (None, json!({})),
+                (
+                    Some(IdentifierData::Null),
+                    json!({
+                        "data": json!(null),
+                    }),
+                ),
+                (
+                    Some(IdentifierData::Multiple(vec![])),
+                    json!({
+                        "data": json!([]),
+                    }),
+                ),
+            ] {
+                let relationship = Relationship {
+                    meta: None,
+                    links: None,
+                    data,
+                };
+
+                let json = serde_json::to_string(&relationship).unwrap();
+
+                assert_eq!(
+                    serde_json::from_str::<Value>(&json).unwrap(),
+                    value
+                );
+
+                let deserialized: Relationship =
+                    serde_json::from_str(&json).unwrap();
+
+                assert_eq!(deserialized, relationship);
+            }
+        }
+
         #[test]
         fn links_empty() {
             let links = Links {
-                other: HashMap::new(),
+                other: Map::new(),
                 self_: None,
                 related: None,
                 first: None,
@@ -364,25 +538,14 @@ mod tests {
 
             let value: Value = serde_json::from_str(&json).unwrap();
 
-            assert_eq!(
-                value,
-                json!({
-                    "self": json!(null),
-                    "related": json!(null),
-                    "first": json!(null),
-                    "last": json!(null),
-                    "prev": json!(null),
-                    "next": json!(null),
-                    "about": json!(null),
-                })
-            );
+            assert_eq!(value, json!({}));
         }
 
         #[test]
         fn links_default() {
             let links = Links {
                 other: {
-                    let mut other = HashMap::new();
+                    let mut other = Map::new();
                     other.insert(
                         "foo".into(),
                         Link::String("http://foo.com".into()),
@@ -406,7 +569,7 @@ mod tests {
                 prev: Some(Link::Object(LinkObject {
                     href: "http://prev.com".into(),
                     meta: Some({
-                        let mut meta = HashMap::new();
+                        let mut meta = Map::new();
                         meta.insert("qwerty".into(), json!(123456));
                         meta
                     }),
@@ -426,7 +589,6 @@ mod tests {
                     "related": json!("http://related.com"),
                     "first": json!({
                         "href": json!("http://first.com"),
-                        "meta": json!(null),
                     }),
                     "last": json!("http://last.com"),
                     "prev": json!({
@@ -438,7 +600,6 @@ mod tests {
                     "foo": json!("http://foo.com"),
                     "bar": json!({
                         "href": json!("http://bar.com"),
-                        "meta": json!(null),
                     }),
                 })
             );
@@ -449,6 +610,7 @@ mod tests {
             let resource = Resource {
                 type_: "qwerties".into(),
                 id: None,
+                lid: None,
                 meta: None,
                 links: None,
                 attributes: None,
@@ -463,13 +625,83 @@ mod tests {
                 value,
                 json!({
                     "type": json!("qwerties"),
-                    "id": json!(null),
-                    "meta": json!(null),
-                    "links": json!(null),
-                    "attributes": json!(null),
-                    "relationships": json!(null),
                 })
             );
         }
+
+        #[test]
+        fn links_order() {
+            let links = Links {
+                other: {
+                    let mut other = Map::new();
+                    other.insert(
+                        "bar".into(),
+                        Link::String("http://bar.com".into()),
+                    );
+                    other.insert(
+                        "foo".into(),
+                        Link::String("http://foo.com".into()),
+                    );
+                    other
+                },
+                self_: Some(Link::String("http://self.com".into())),
+                related: None,
+                first: None,
+                last: None,
+                prev: None,
+                next: Some(Link::String("http://next.com".into())),
+                about: Some(Link::String("http://about.com".into())),
+            };
+
+            assert_eq!(
+                serde_json::to_string(&links).unwrap(),
+                "{\
+                    \"self\":\"http://self.com\",\
+                    \"next\":\"http://next.com\",\
+                    \"about\":\"http://about.com\",\
+                    \"bar\":\"http://bar.com\",\
+                    \"foo\":\"http://foo.com\"\
+                }",
+            );
+        }
+
+        #[test]
+        fn reproducible() {
+            let json = serde_json::to_string(&Document {
+                jsonapi: None,
+                meta: Some(fixtures::meta_or_attrs()),
+                links: Some(fixtures::different_links()),
+                data: Some(Data::Single(Resource {
+                    type_: "qwerties".into(),
+                    id: Some("123".into()),
+                    lid: None,
+                    meta: Some(fixtures::meta_or_attrs()),
+                    links: None,
+                    attributes: Some(fixtures::meta_or_attrs()),
+                    relationships: Some(expected_relationships()),
+                })),
+                errors: None,
+                included: None,
+            })
+            .unwrap();
+
+            for _ in 0..10 {
+                let document: Document = serde_json::from_str(&json).unwrap();
+
+                assert_eq!(serde_json::to_string(&document).unwrap(), json);
+            }
+        }
+
+        #[test]
+        fn members_in_insertion_order() {
+            let mut meta = MetaOrAttrs::new();
+            meta.insert("foo".into(), json!(1));
+            meta.insert("bar".into(), json!(2));
+
+            assert_eq!(
+                serde_json::to_string(&meta).unwrap(),
+                "{\"foo\":1,\"bar\":2}",
+            );
+        }
     }
 }
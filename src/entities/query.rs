@@ -0,0 +1,686 @@
+use super::*;
+
+use crate::member_name;
+
+// Query parameters of fetch requests
+// (https://jsonapi.org/format/#query-parameters-families).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Query {
+    pub include: Vec<String>,
+    pub fields: Map<String, Vec<String>>,
+    pub sort: Vec<SortField>,
+    pub page: Map<String, String>,
+    pub filter: Map<String, String>,
+    // Implementation-specific parameters by their full names
+    // (https://jsonapi.org/format/#query-parameters-custom).
+    pub other: Map<String, String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SortField {
+    pub field: String,
+    pub direction: SortDirection,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn include<P: ToString>(self, path: P) -> Self {
+        let mut include = self.include;
+        include.push(path.to_string());
+
+        Self { include, ..self }
+    }
+
+    pub fn fields<T, I, F>(self, type_: T, fields: I) -> Self
+    where
+        T: ToString,
+        I: IntoIterator<Item = F>,
+        F: ToString,
+    {
+        let mut all_fields = self.fields;
+        all_fields.insert(
+            type_.to_string(),
+            fields.into_iter().map(|field| field.to_string()).collect(),
+        );
+
+        Self {
+            fields: all_fields,
+            ..self
+        }
+    }
+
+    pub fn sort<F: ToString>(self, field: F) -> Self {
+        self.sort_by(field, SortDirection::Ascending)
+    }
+
+    pub fn sort_desc<F: ToString>(self, field: F) -> Self {
+        self.sort_by(field, SortDirection::Descending)
+    }
+
+    pub fn sort_by<F: ToString>(
+        self,
+        field: F,
+        direction: SortDirection,
+    ) -> Self {
+        let mut sort = self.sort;
+        sort.push(SortField {
+            field: field.to_string(),
+            direction,
+        });
+
+        Self { sort, ..self }
+    }
+
+    pub fn page<N: ToString, V: ToString>(self, name: N, value: V) -> Self {
+        let mut page = self.page;
+        page.insert(name.to_string(), value.to_string());
+
+        Self { page, ..self }
+    }
+
+    pub fn filter<N: ToString, V: ToString>(self, name: N, value: V) -> Self {
+        let mut filter = self.filter;
+        filter.insert(name.to_string(), value.to_string());
+
+        Self { filter, ..self }
+    }
+
+    pub fn other<N: ToString, V: ToString>(self, name: N, value: V) -> Self {
+        let mut other = self.other;
+        other.insert(name.to_string(), value.to_string());
+
+        Self { other, ..self }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty()
+            && self.fields.is_empty()
+            && self.sort.is_empty()
+            && self.page.is_empty()
+            && self.filter.is_empty()
+            && self.other.is_empty()
+    }
+
+    // Names and values of the query parameters, not encoded.
+    pub fn params(&self) -> Vec<(String, String)> {
+        let mut params = vec![];
+
+        if !self.include.is_empty() {
+            params.push(("include".into(), self.include.join(",")));
+        }
+
+        for (type_, fields) in &self.fields {
+            params.push((family_param("fields", type_), fields.join(",")));
+        }
+
+        if !self.sort.is_empty() {
+            let sort: Vec<String> =
+                self.sort.iter().map(|field| field.to_string()).collect();
+
+            params.push(("sort".into(), sort.join(",")));
+        }
+
+        for (name, value) in &self.page {
+            params.push((family_param("page", name), value.clone()));
+        }
+
+        for (name, value) in &self.filter {
+            params.push((family_param("filter", name), value.clone()));
+        }
+
+        for (name, value) in &self.other {
+            params.push((name.clone(), value.clone()));
+        }
+
+        params
+    }
+}
+
+// Encoded query string without the leading "?".
+impl Display for Query {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        for (index, (name, value)) in self.params().iter().enumerate() {
+            if index != 0 {
+                formatter.write_str("&")?;
+            }
+
+            write!(formatter, "{}={}", encode(name), encode(value))?;
+        }
+
+        Ok(())
+    }
+}
+
+// Parses the query string of a fetch request. Malformed parameters and
+// unknown ones which the specification reserves are rejected with an error
+// object as it requires (https://jsonapi.org/format/#query-parameters).
+impl FromStr for Query {
+    type Err = ErrorObject;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut query = Self::new();
+        let mut seen = vec![];
+
+        for param in s.strip_prefix('?').unwrap_or(s).split('&') {
+            if param.is_empty() {
+                continue;
+            }
+
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+
+            let name = decode(name).ok_or_else(|| {
+                invalid_parameter(name, "The name is not properly encoded")
+            })?;
+            let value = decode(value).ok_or_else(|| {
+                invalid_parameter(&name, "The value is not properly encoded")
+            })?;
+
+            if seen.contains(&name) {
+                return Err(invalid_parameter(
+                    &name,
+                    "The parameter is given more than once",
+                ));
+            }
+
+            query
+                .add_param(&name, &value)
+                .map_err(|detail| invalid_parameter(&name, detail))?;
+            seen.push(name);
+        }
+
+        Ok(query)
+    }
+}
+
+impl IntoIterator for &Query {
+    type Item = (String, String);
+    type IntoIter = std::vec::IntoIter<(String, String)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.params().into_iter()
+    }
+}
+
+impl Display for SortField {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        if self.direction == SortDirection::Descending {
+            formatter.write_str("-")?;
+        }
+
+        formatter.write_str(&self.field)
+    }
+}
+
+impl Query {
+    fn add_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "include" => {
+                for path in value.split(',').filter(|path| !path.is_empty()) {
+                    if !path.split('.').all(member_name::is_valid) {
+                        return Err(format!(
+                            "Invalid relationship path: {}",
+                            path
+                        ));
+                    }
+
+                    self.include.push(path.into());
+                }
+            }
+            "sort" => {
+                for field in value.split(',') {
+                    let (field, direction) = match field.strip_prefix('-') {
+                        Some(field) => (field, SortDirection::Descending),
+                        None => (field, SortDirection::Ascending),
+                    };
+
+                    if !field.split('.').all(member_name::is_valid) {
+                        return Err(format!("Invalid sort field: {:?}", field));
+                    }
+
+                    self.sort.push(SortField {
+                        field: field.into(),
+                        direction,
+                    });
+                }
+            }
+            _ => {
+                let (family, member) = split_family(name)
+                    .ok_or_else(|| "Malformed query parameter".to_string())?;
+
+                match (family, member) {
+                    ("fields", Some(type_)) => {
+                        if !member_name::is_valid(&type_) {
+                            return Err(format!("Invalid type: {}", type_));
+                        }
+
+                        let mut fields = vec![];
+
+                        for field in
+                            value.split(',').filter(|field| !field.is_empty())
+                        {
+                            if !member_name::is_valid(field) {
+                                return Err(format!(
+                                    "Invalid field name: {}",
+                                    field
+                                ));
+                            }
+
+                            fields.push(field.into());
+                        }
+
+                        self.fields.insert(type_, fields);
+                    }
+                    // The specification leaves the members of these families
+                    // to the implementation, they may even be absent.
+                    ("page", member) => {
+                        self.page
+                            .insert(member.unwrap_or_default(), value.into());
+                    }
+                    ("filter", member) => {
+                        self.filter
+                            .insert(member.unwrap_or_default(), value.into());
+                    }
+                    // Names which consist of lowercase letters only are
+                    // reserved for the specification.
+                    (family, _)
+                        if family
+                            .bytes()
+                            .all(|byte| byte.is_ascii_lowercase()) =>
+                    {
+                        return Err("Unsupported query parameter".to_string());
+                    }
+                    (family, _) if !member_name::is_valid(family) => {
+                        return Err("Invalid query parameter name".to_string());
+                    }
+                    _ => {
+                        self.other.insert(name.into(), value.into());
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Splits "family[member]" into its parts, the member is absent if there are
+// no brackets (https://jsonapi.org/format/#query-parameters-families).
+// Members may be nested, e.g. the member of "page[cursor][after]" is
+// "cursor[after]". Brackets are kept when the first part is empty, e.g. the
+// member of "page[]" is "[]".
+fn split_family(name: &str) -> Option<(&str, Option<String>)> {
+    let (family, mut rest) = match name.find('[') {
+        None => return Some((name, None)),
+        Some(index) => name.split_at(index),
+    };
+
+    let mut member = String::new();
+
+    while !rest.is_empty() {
+        let (segment, next) = rest.strip_prefix('[')?.split_once(']')?;
+
+        if segment.contains('[') {
+            return None;
+        }
+
+        if member.is_empty() && !segment.is_empty() {
+            member.push_str(segment);
+        } else {
+            member.push_str(&format!("[{}]", segment));
+        }
+
+        rest = next;
+    }
+
+    Some((family, Some(member)))
+}
+
+// Inverse of `split_family`.
+fn family_param(family: &str, member: &str) -> String {
+    if member.is_empty() || member.starts_with('[') {
+        return format!("{}{}", family, member);
+    }
+
+    match member.split_once('[') {
+        None => format!("{}[{}]", family, member),
+        Some((first, rest)) => format!("{}[{}][{}", family, first, rest),
+    }
+}
+
+fn invalid_parameter<D: ToString>(name: &str, detail: D) -> ErrorObject {
+    ErrorObject {
+        id: None,
+        links: None,
+        status: Some(HttpStatus::BAD_REQUEST),
+        code: None,
+        title: Some("Invalid Query Parameter".into()),
+        detail: Some(detail.to_string()),
+        source: Some(ErrorSource {
+            pointer: None,
+            parameter: Some(name.into()),
+        }),
+        meta: None,
+    }
+}
+
+fn decode(s: &str) -> Option<String> {
+    let mut decoded = vec![];
+    let mut bytes = s.bytes();
+
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let high = (bytes.next()? as char).to_digit(16)?;
+                let low = (bytes.next()? as char).to_digit(16)?;
+                decoded.push((high * 16 + low) as u8);
+            }
+            _ => decoded.push(byte),
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+// Same as "application/x-www-form-urlencoded" which is used by the clients.
+fn encode(s: &str) -> String {
+    let mut encoded = String::new();
+
+    for byte in s.bytes() {
+        match byte {
+            b'a'..=b'z'
+            | b'A'..=b'Z'
+            | b'0'..=b'9'
+            | b'*'
+            | b'-'
+            | b'.'
+            | b'_' => encoded.push(byte as char),
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query() -> Query {
+        Query::new()
+            .include("author")
+            .include("comments.author")
+            .fields("articles", ["title", "body"])
+            .fields("people", ["name"])
+            .sort_desc("created")
+            .sort("title")
+            .page("number", 2)
+            .page("size", 10)
+            .filter("author", "Dan Gebhardt")
+    }
+
+    #[test]
+    fn empty() {
+        let query = Query::new();
+
+        assert!(query.is_empty());
+        assert_eq!(query.params(), vec![]);
+        assert_eq!(query.to_string(), "");
+    }
+
+    #[test]
+    fn params() {
+        let query = query();
+
+        assert!(!query.is_empty());
+        assert_eq!(
+            query.params(),
+            vec![
+                ("include".into(), "author,comments.author".into()),
+                ("fields[articles]".into(), "title,body".into()),
+                ("fields[people]".into(), "name".into()),
+                ("sort".into(), "-created,title".into()),
+                ("page[number]".into(), "2".into()),
+                ("page[size]".into(), "10".into()),
+                ("filter[author]".into(), "Dan Gebhardt".into()),
+            ] as Vec<(String, String)>,
+        );
+    }
+
+    #[test]
+    fn encoded() {
+        assert_eq!(
+            query().to_string(),
+            "include=author%2Ccomments.author\
+                &fields%5Barticles%5D=title%2Cbody\
+                &fields%5Bpeople%5D=name\
+                &sort=-created%2Ctitle\
+                &page%5Bnumber%5D=2\
+                &page%5Bsize%5D=10\
+                &filter%5Bauthor%5D=Dan+Gebhardt",
+        );
+    }
+
+    #[test]
+    fn sort_field() {
+        let query = Query::new()
+            .sort_by("title", SortDirection::Descending)
+            .sort_by("created", SortDirection::Ascending);
+
+        assert_eq!(
+            query.sort,
+            vec![
+                SortField {
+                    field: "title".into(),
+                    direction: SortDirection::Descending,
+                },
+                SortField {
+                    field: "created".into(),
+                    direction: SortDirection::Ascending,
+                },
+            ],
+        );
+        assert_eq!(query.sort[0].to_string(), "-title");
+        assert_eq!(query.sort[1].to_string(), "created");
+    }
+
+    #[test]
+    fn replace_fields_and_page() {
+        let query = Query::new()
+            .fields("articles", ["title"])
+            .fields("articles", ["body"])
+            .page("number", 1)
+            .page("number", 3);
+
+        assert_eq!(
+            query.params(),
+            vec![
+                ("fields[articles]".into(), "body".into()),
+                ("page[number]".into(), "3".into()),
+            ] as Vec<(String, String)>,
+        );
+    }
+
+    #[test]
+    fn encode_special_characters() {
+        assert_eq!(encode("a b&c=d/é"), "a+b%26c%3Dd%2F%C3%A9");
+        assert_eq!(encode("*-._~"), "*-._%7E");
+    }
+
+    fn assert_invalid(s: &str, parameter: &str) {
+        let error = s.parse::<Query>().unwrap_err();
+
+        assert_eq!(error.status, Some(HttpStatus::BAD_REQUEST));
+        assert_eq!(
+            error.source,
+            Some(ErrorSource {
+                pointer: None,
+                parameter: Some(parameter.into()),
+            }),
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let parsed: Query = "?include=author,comments.author\
+            &fields%5Barticles%5D=title%2Cbody\
+            &fields[people]=name\
+            &sort=-created,title\
+            &page[number]=2\
+            &page[size]=10\
+            &filter[author]=Dan+Gebhardt"
+            .parse()
+            .unwrap();
+
+        assert_eq!(parsed, query());
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!("".parse::<Query>().unwrap(), Query::new());
+        assert_eq!("?".parse::<Query>().unwrap(), Query::new());
+        assert_eq!("&&".parse::<Query>().unwrap(), Query::new());
+    }
+
+    #[test]
+    fn parse_empty_fields() {
+        let query: Query = "fields[articles]=".parse().unwrap();
+
+        assert_eq!(query.fields.get("articles"), Some(&vec![]));
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        assert_eq!(query().to_string().parse::<Query>().unwrap(), query());
+    }
+
+    #[test]
+    fn parse_unsupported() {
+        assert_invalid("foo=bar", "foo");
+        assert_invalid("fields=title", "fields");
+        assert_invalid("include[]=author", "include[]");
+        assert_invalid("sorting[title]=asc", "sorting[title]");
+        assert_invalid("-camelCase=1", "-camelCase");
+    }
+
+    #[test]
+    fn parse_malformed_family() {
+        assert_invalid("fields[articles=title", "fields[articles");
+        assert_invalid("fields[articles][x]=title", "fields[articles][x]");
+        assert_invalid("fields[]=title", "fields[]");
+        assert_invalid("page[number]x=1", "page[number]x");
+        assert_invalid("page[a[b]]=1", "page[a[b]]");
+    }
+
+    #[test]
+    fn parse_nested_members() {
+        let parsed: Query =
+            "filter[author.name]=Dan&page[cursor][after]=abc&page[ size]=10"
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            parsed,
+            Query::new()
+                .filter("author.name", "Dan")
+                .page("cursor[after]", "abc")
+                .page(" size", 10),
+        );
+        assert_eq!(
+            parsed.params(),
+            vec![
+                ("page[cursor][after]".into(), "abc".into()),
+                ("page[ size]".into(), "10".into()),
+                ("filter[author.name]".into(), "Dan".into()),
+            ] as Vec<(String, String)>,
+        );
+        assert_eq!(parsed.to_string().parse::<Query>().unwrap(), parsed);
+    }
+
+    #[test]
+    fn parse_bare_and_empty_members() {
+        let parsed: Query = "page=2&filter=recent&filter[]=a&page[][size]=10"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            parsed,
+            Query::new()
+                .page("", 2)
+                .page("[][size]", 10)
+                .filter("", "recent")
+                .filter("[]", "a"),
+        );
+        assert_eq!(
+            parsed.params(),
+            vec![
+                ("page".into(), "2".into()),
+                ("page[][size]".into(), "10".into()),
+                ("filter".into(), "recent".into()),
+                ("filter[]".into(), "a".into()),
+            ] as Vec<(String, String)>,
+        );
+        assert_eq!(parsed.to_string().parse::<Query>().unwrap(), parsed);
+    }
+
+    #[test]
+    fn parse_implementation_specific() {
+        let parsed: Query =
+            "camelCase=1&page_size=10&myFilter[a][b]=2".parse().unwrap();
+
+        assert_eq!(
+            parsed,
+            Query::new()
+                .other("camelCase", 1)
+                .other("page_size", 10)
+                .other("myFilter[a][b]", 2),
+        );
+        assert_eq!(parsed.to_string().parse::<Query>().unwrap(), parsed);
+    }
+
+    #[test]
+    fn parse_invalid_values() {
+        assert_invalid("include=author..name", "include");
+        assert_invalid("sort=title,", "sort");
+        assert_invalid("sort=--title", "sort");
+        assert_invalid("fields[articles]=title,-body", "fields[articles]");
+    }
+
+    #[test]
+    fn parse_duplicate() {
+        assert_invalid("sort=title&sort=created", "sort");
+        assert_invalid("page[size]=1&page[size]=2", "page[size]");
+    }
+
+    #[test]
+    fn parse_invalid_encoding() {
+        assert_invalid("include%=author", "include%");
+        assert_invalid("include=%zz", "include");
+        assert_invalid("include=%C3", "include");
+    }
+
+    #[test]
+    fn invalid_parameter_error() {
+        let error = "foo=bar".parse::<Query>().unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(error).unwrap(),
+            serde_json::json!({
+                "status": "400",
+                "title": "Invalid Query Parameter",
+                "detail": "Unsupported query parameter",
+                "source": { "parameter": "foo" },
+            }),
+        );
+    }
+}
@@ -4,7 +4,14 @@ impl Entity<'_> for Relationship {}
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Relationship {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<MetaOrAttrs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<Links>,
-    pub data: Option<Data>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_present",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub data: Option<IdentifierData>,
 }
@@ -2,4 +2,4 @@ use super::*;
 
 impl Entity<'_> for Relationships {}
 
-pub type Relationships = HashMap<String, Relationship>;
+pub type Relationships = Map<String, Relationship>;
@@ -6,9 +6,16 @@ impl Entity<'_> for Resource {}
 pub struct Resource {
     #[serde(rename = "type")]
     pub type_: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<MetaOrAttrs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<Links>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<MetaOrAttrs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub relationships: Option<Relationships>,
 }
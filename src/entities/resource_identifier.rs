@@ -0,0 +1,128 @@
+use super::*;
+
+impl Entity<'_> for ResourceIdentifier {}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResourceIdentifier {
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<MetaOrAttrs>,
+}
+
+impl From<&Resource> for ResourceIdentifier {
+    fn from(resource: &Resource) -> Self {
+        Self {
+            type_: resource.type_.clone(),
+            id: resource.id.clone(),
+            lid: resource.lid.clone(),
+            meta: None,
+        }
+    }
+}
+
+impl From<Resource> for ResourceIdentifier {
+    fn from(resource: Resource) -> Self {
+        Self {
+            type_: resource.type_,
+            id: resource.id,
+            lid: resource.lid,
+            meta: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    use serde_json::json;
+
+    #[test]
+    fn from_resource() {
+        let resource = Resource {
+            type_: "qwerties".into(),
+            id: Some("123".into()),
+            lid: Some("local-1".into()),
+            meta: Some(fixtures::meta_or_attrs()),
+            links: Some(fixtures::simple_links()),
+            attributes: Some(fixtures::meta_or_attrs()),
+            relationships: None,
+        };
+
+        let expected = ResourceIdentifier {
+            type_: "qwerties".into(),
+            id: Some("123".into()),
+            lid: Some("local-1".into()),
+            meta: None,
+        };
+
+        assert_eq!(ResourceIdentifier::from(&resource), expected);
+        assert_eq!(ResourceIdentifier::from(resource), expected);
+    }
+
+    #[test]
+    fn deserialize() {
+        let value = json!({
+            "type": json!("qwerties"),
+            "id": json!("123"),
+            "meta": fixtures::meta_or_attrs_value(),
+        });
+
+        let resource_identifier: ResourceIdentifier =
+            serde_json::from_value(value).unwrap();
+
+        assert_eq!(
+            resource_identifier,
+            ResourceIdentifier {
+                type_: "qwerties".into(),
+                id: Some("123".into()),
+                lid: None,
+                meta: Some(fixtures::meta_or_attrs()),
+            },
+        );
+    }
+
+    #[test]
+    fn deserialize_with_lid() {
+        let value = json!({
+            "type": json!("qwerties"),
+            "lid": json!("local-1"),
+        });
+
+        let resource_identifier: ResourceIdentifier =
+            serde_json::from_value(value).unwrap();
+
+        assert_eq!(
+            resource_identifier,
+            ResourceIdentifier {
+                type_: "qwerties".into(),
+                id: None,
+                lid: Some("local-1".into()),
+                meta: None,
+            },
+        );
+    }
+
+    #[test]
+    fn deserialize_rejects_resource_members() {
+        for member in ["attributes", "relationships", "links"] {
+            let mut value = json!({
+                "type": json!("qwerties"),
+                "id": json!("123"),
+            });
+
+            value[member] = json!({});
+
+            assert!(
+                serde_json::from_value::<ResourceIdentifier>(value).is_err()
+            );
+        }
+    }
+}
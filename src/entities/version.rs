@@ -182,6 +182,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::nonminimal_bool)]
     fn order() {
         assert!(!(Version::new(0) < Version::new(0)));
         assert!(Version::new(0) <= Version::new(0));
@@ -1,7 +1,5 @@
 use super::*;
 
-use std::collections::HashMap;
-
 use serde_json::{json, Value};
 
 /*****************
@@ -31,7 +29,7 @@ pub fn meta_or_attrs_value() -> Value {
 pub fn simple_links() -> Links {
     Links {
         other: {
-            let mut other = HashMap::new();
+            let mut other = Map::new();
             other.insert("qwe".into(), Link::String("http://qwe.com".into()));
             other
         },
@@ -56,7 +54,7 @@ pub fn simple_links() -> Links {
 
 pub fn different_links() -> Links {
     let mut links: Links = Links {
-        other: HashMap::new(),
+        other: Map::new(),
         self_: Some(Link::String("http://example.com".into())),
         related: None,
         first: None,
@@ -86,10 +84,6 @@ pub fn different_links() -> Links {
 pub fn different_links_value() -> Value {
     json!({
         "self": json!("http://example.com"),
-        "related": json!(null),
-        "first": json!(null),
-        "last": json!(null),
-        "prev": json!(null),
         "next": json!({
             "href": json!("http://example.com"),
             "meta": meta_or_attrs_value(),
@@ -99,7 +93,6 @@ pub fn different_links_value() -> Value {
             "href": json!("http://bar.com"),
             "meta": meta_or_attrs_value(),
         }),
-        "about": json!(null),
     })
 }
 
@@ -171,3 +164,84 @@ pub fn full_error_value() -> Value {
         "meta": meta_or_attrs_value(),
     })
 }
+
+/*******************
+ * article, tags   *
+ *                 *
+ * For the clients *
+ *******************/
+
+#[cfg(any(feature = "client", feature = "client-async"))]
+pub fn article() -> Document {
+    DocumentBuilder::default()
+        .data(
+            ResourceBuilder::new_with_id("articles", "1")
+                .attr("title", "Updated"),
+        )
+        .unwrap()
+}
+
+#[cfg(any(feature = "client", feature = "client-async"))]
+pub fn article_value() -> Value {
+    json!({
+        "data": {
+            "type": "articles",
+            "id": "1",
+            "attributes": { "title": "Updated" },
+        },
+    })
+}
+
+#[cfg(any(feature = "client", feature = "client-async"))]
+pub fn tags() -> Vec<ResourceIdentifier> {
+    vec![
+        ResourceIdentifierBuilder::new("tags", "2").unwrap(),
+        ResourceIdentifierBuilder::new("tags", "3").unwrap(),
+    ]
+}
+
+#[cfg(any(feature = "client", feature = "client-async"))]
+pub fn tags_value() -> Value {
+    json!({
+        "data": [
+            { "type": "tags", "id": "2" },
+            { "type": "tags", "id": "3" },
+        ],
+    })
+}
+
+// A page of a collection with a single article and an optional "next" link.
+#[cfg(any(feature = "client", feature = "client-async"))]
+pub fn page_value(id: &str, next: Option<Value>) -> Value {
+    let mut value = json!({
+        "data": [{ "type": "articles", "id": id }],
+    });
+
+    if let Some(next) = next {
+        value["links"] = json!({ "next": next });
+    }
+
+    value
+}
+
+// The "author" relationship has only linkage data and "tags" has no links
+// object, so only "article" can be fetched.
+#[cfg(any(feature = "client", feature = "client-async"))]
+pub fn comment_with_related_link() -> Resource {
+    serde_json::from_value(json!({
+        "type": "comments",
+        "id": "5",
+        "relationships": {
+            "article": {
+                "links": { "related": "/comments/5/article" },
+            },
+            "author": {
+                "data": { "type": "people", "id": "2" },
+            },
+            "tags": {
+                "meta": { "count": 0 },
+            },
+        },
+    }))
+    .unwrap()
+}
@@ -0,0 +1,388 @@
+use super::*;
+
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+pub struct DocumentGraph<'a> {
+    resources: HashMap<(&'a str, &'a str), &'a Resource>,
+    local_resources: HashMap<(&'a str, &'a str), &'a Resource>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Resolved<'a> {
+    Single(&'a Resource),
+    Multiple(Vec<&'a Resource>),
+}
+
+impl<'a> DocumentGraph<'a> {
+    pub fn new(document: &'a Document) -> Self {
+        let mut resources = HashMap::new();
+        let mut local_resources = HashMap::new();
+
+        let data = match &document.data {
+            None | Some(Data::Null) => &[][..],
+            Some(Data::Single(resource)) => std::slice::from_ref(resource),
+            Some(Data::Multiple(resources)) => &resources[..],
+        };
+
+        let included = match &document.included {
+            None => &[][..],
+            Some(included) => &included[..],
+        };
+
+        for resource in data.iter().chain(included) {
+            if let Some(id) = &resource.id {
+                resources
+                    .entry((resource.type_.as_str(), id.as_str()))
+                    .or_insert(resource);
+            }
+
+            if let Some(lid) = &resource.lid {
+                local_resources
+                    .entry((resource.type_.as_str(), lid.as_str()))
+                    .or_insert(resource);
+            }
+        }
+
+        Self {
+            resources,
+            local_resources,
+        }
+    }
+
+    pub fn get(&self, type_: &str, id: &str) -> Option<&'a Resource> {
+        self.resources.get(&(type_, id)).copied()
+    }
+
+    pub fn get_by_lid(&self, type_: &str, lid: &str) -> Option<&'a Resource> {
+        self.local_resources.get(&(type_, lid)).copied()
+    }
+
+    /// Resolves a relationship path like `"author"` or `"comments.author"`
+    /// starting from the given resource. The result is a single resource if
+    /// every relationship on the path is to-one, and a list of unique
+    /// resources otherwise. Linkage to resources which are absent from the
+    /// document is skipped.
+    ///
+    /// Returns `None` if a relationship is not present, has no linkage data,
+    /// or if a to-one relationship points to a resource which is not in the
+    /// document.
+    pub fn resolve(
+        &self,
+        resource: &Resource,
+        path: &str,
+    ) -> Option<Resolved<'a>> {
+        let mut names = path.split('.');
+
+        let mut resolved = self.resolve_one(resource, names.next()?)?;
+
+        for name in names {
+            resolved = match resolved {
+                Resolved::Single(resource) => {
+                    self.resolve_one(resource, name)?
+                }
+                Resolved::Multiple(resources) => {
+                    let mut new_resources: Vec<&'a Resource> = vec![];
+
+                    for resource in resources {
+                        let next = match self.resolve_one(resource, name) {
+                            None => continue,
+                            Some(next) => next.into_vec(),
+                        };
+
+                        for resource in next {
+                            if !new_resources
+                                .iter()
+                                .any(|other| std::ptr::eq(*other, resource))
+                            {
+                                new_resources.push(resource);
+                            }
+                        }
+                    }
+
+                    Resolved::Multiple(new_resources)
+                }
+            };
+        }
+
+        Some(resolved)
+    }
+
+    fn resolve_one(
+        &self,
+        resource: &Resource,
+        name: &str,
+    ) -> Option<Resolved<'a>> {
+        let relationship = resource.relationships.as_ref()?.get(name)?;
+
+        Some(match relationship.data.as_ref()? {
+            IdentifierData::Null => return None,
+            IdentifierData::Single(linkage) => {
+                Resolved::Single(self.find(linkage)?)
+            }
+            IdentifierData::Multiple(linkages) => Resolved::Multiple(
+                linkages
+                    .iter()
+                    .filter_map(|linkage| self.find(linkage))
+                    .collect(),
+            ),
+        })
+    }
+
+    fn find(&self, linkage: &ResourceIdentifier) -> Option<&'a Resource> {
+        let by_id = match &linkage.id {
+            None => None,
+            Some(id) => self.get(&linkage.type_, id),
+        };
+
+        match (by_id, &linkage.lid) {
+            (Some(resource), _) => Some(resource),
+            (None, None) => None,
+            (None, Some(lid)) => self.get_by_lid(&linkage.type_, lid),
+        }
+    }
+}
+
+impl<'a> From<&'a Document> for DocumentGraph<'a> {
+    fn from(document: &'a Document) -> Self {
+        Self::new(document)
+    }
+}
+
+impl<'a> Resolved<'a> {
+    pub fn into_vec(self) -> Vec<&'a Resource> {
+        match self {
+            Self::Single(resource) => vec![resource],
+            Self::Multiple(resources) => resources,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document() -> Document {
+        DocumentBuilder::default()
+            .data(vec![
+                ResourceBuilder::new_with_id("articles", "1")
+                    .attr("title", "First")
+                    .rel(
+                        "author",
+                        ResourceIdentifierBuilder::new("people", "9"),
+                    )
+                    .rel(
+                        "comments",
+                        RelationshipBuilder::default().data(vec![
+                            ResourceIdentifierBuilder::new("comments", "5"),
+                            ResourceIdentifierBuilder::new("comments", "12"),
+                        ]),
+                    ),
+                ResourceBuilder::new_with_id("articles", "2")
+                    .attr("title", "Second")
+                    .rel(
+                        "author",
+                        ResourceIdentifierBuilder::new("people", "404"),
+                    )
+                    .rel(
+                        "comments",
+                        RelationshipBuilder::default().data(vec![
+                            ResourceIdentifierBuilder::new("comments", "12"),
+                            ResourceIdentifierBuilder::new("comments", "404"),
+                        ]),
+                    ),
+            ])
+            .include(
+                ResourceBuilder::new_with_id("people", "9").attr("name", "Dan"),
+            )
+            .include(
+                ResourceBuilder::new_with_id("comments", "5")
+                    .attr("body", "First!")
+                    .rel(
+                        "author",
+                        ResourceIdentifierBuilder::new("people", "2"),
+                    ),
+            )
+            .include(
+                ResourceBuilder::new_with_id("comments", "12")
+                    .attr("body", "I like XML better")
+                    .rel(
+                        "author",
+                        ResourceIdentifierBuilder::new("people", "9"),
+                    ),
+            )
+            .include(
+                ResourceBuilder::new_with_id("people", "2")
+                    .attr("name", "Alice"),
+            )
+            .unwrap()
+    }
+
+    fn primary(document: &Document, index: usize) -> &Resource {
+        match &document.data {
+            Some(Data::Multiple(resources)) => &resources[index],
+            _ => unreachable!(),
+        }
+    }
+
+    fn ids(resolved: Resolved<'_>) -> Vec<(&str, &str)> {
+        resolved
+            .into_vec()
+            .into_iter()
+            .map(|resource| {
+                (
+                    resource.type_.as_str(),
+                    resource.id.as_ref().unwrap().as_str(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn get() {
+        let document = document();
+        let graph = DocumentGraph::new(&document);
+
+        assert_eq!(graph.get("articles", "2"), Some(primary(&document, 1)));
+        assert_eq!(
+            graph
+                .get("people", "9")
+                .unwrap()
+                .attributes
+                .as_ref()
+                .unwrap()["name"],
+            "Dan",
+        );
+        assert_eq!(graph.get("people", "404"), None);
+        assert_eq!(graph.get("comments", "9"), None);
+    }
+
+    #[test]
+    fn resolve_to_one() {
+        let document = document();
+        let graph = DocumentGraph::new(&document);
+
+        assert_eq!(
+            graph.resolve(primary(&document, 0), "author"),
+            Some(Resolved::Single(graph.get("people", "9").unwrap())),
+        );
+    }
+
+    #[test]
+    fn resolve_to_one_missing() {
+        let document = document();
+        let graph = DocumentGraph::new(&document);
+
+        assert_eq!(graph.resolve(primary(&document, 1), "author"), None);
+        assert_eq!(graph.resolve(primary(&document, 0), "unknown"), None);
+    }
+
+    #[test]
+    fn resolve_to_many() {
+        let document = document();
+        let graph = DocumentGraph::new(&document);
+
+        assert_eq!(
+            ids(graph.resolve(primary(&document, 0), "comments").unwrap()),
+            vec![("comments", "5"), ("comments", "12")],
+        );
+        assert_eq!(
+            ids(graph.resolve(primary(&document, 1), "comments").unwrap()),
+            vec![("comments", "12")],
+        );
+    }
+
+    #[test]
+    fn resolve_path() {
+        let document = document();
+        let graph = DocumentGraph::new(&document);
+
+        assert_eq!(
+            ids(graph
+                .resolve(primary(&document, 0), "comments.author")
+                .unwrap()),
+            vec![("people", "2"), ("people", "9")],
+        );
+        assert_eq!(
+            ids(graph
+                .resolve(primary(&document, 1), "comments.author")
+                .unwrap()),
+            vec![("people", "9")],
+        );
+    }
+
+    #[test]
+    fn resolve_path_to_one() {
+        let document = document();
+        let graph = DocumentGraph::new(&document);
+        let comment = graph.get("comments", "12").unwrap();
+
+        assert_eq!(graph.resolve(comment, "author.unknown"), None,);
+        assert_eq!(
+            graph.resolve(comment, "author"),
+            Some(Resolved::Single(graph.get("people", "9").unwrap())),
+        );
+    }
+
+    #[test]
+    fn resolve_by_lid() {
+        let document = DocumentBuilder::default()
+            .data(
+                ResourceBuilder::new("articles")
+                    .lid("article")
+                    .attr("title", "New")
+                    .rel(
+                        "author",
+                        ResourceIdentifierBuilder::new_with_lid(
+                            "people", "author",
+                        ),
+                    )
+                    .rel(
+                        "tags",
+                        RelationshipBuilder::default().data(vec![
+                            ResourceIdentifierBuilder::new("tags", "1"),
+                            ResourceIdentifierBuilder::new_with_lid(
+                                "tags", "tag",
+                            ),
+                        ]),
+                    ),
+            )
+            .include(
+                ResourceBuilder::new("people")
+                    .lid("author")
+                    .attr("name", "Dan")
+                    .rel(
+                        "articles",
+                        RelationshipBuilder::default().data(vec![
+                            ResourceIdentifierBuilder::new_with_lid(
+                                "articles", "article",
+                            ),
+                        ]),
+                    ),
+            )
+            .include(ResourceBuilder::new("tags").lid("tag"))
+            .include(ResourceBuilder::new_with_id("tags", "1"))
+            .unwrap();
+
+        let graph = DocumentGraph::new(&document);
+        let article = graph.get_by_lid("articles", "article").unwrap();
+        let author = graph.get_by_lid("people", "author").unwrap();
+
+        assert_eq!(graph.get("articles", "article"), None);
+        assert_eq!(
+            graph.resolve(article, "author"),
+            Some(Resolved::Single(author)),
+        );
+        assert_eq!(
+            graph.resolve(article, "tags"),
+            Some(Resolved::Multiple(vec![
+                graph.get("tags", "1").unwrap(),
+                graph.get_by_lid("tags", "tag").unwrap(),
+            ])),
+        );
+        assert_eq!(
+            graph.resolve(article, "author.articles"),
+            Some(Resolved::Multiple(vec![article])),
+        );
+    }
+}
@@ -0,0 +1,52 @@
+// https://datatracker.ietf.org/doc/html/rfc6901
+
+pub(crate) fn is_valid(pointer: &str) -> bool {
+    if pointer.is_empty() {
+        return true;
+    }
+
+    if !pointer.starts_with('/') {
+        return false;
+    }
+
+    let mut chars = pointer.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '~' && !matches!(chars.next(), Some('0' | '1')) {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub(crate) fn escape(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        for pointer in ["", "/", "/foo", "/foo/0/bar", "/a~1b", "/m~0n", "//"] {
+            assert!(is_valid(pointer), "{:?}", pointer);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for pointer in ["foo", "foo/bar", "/~", "/~2", "/foo~"] {
+            assert!(!is_valid(pointer), "{:?}", pointer);
+        }
+    }
+
+    #[test]
+    fn escape_tokens() {
+        assert_eq!(escape("foo"), "foo");
+        assert_eq!(escape("a/b"), "a~1b");
+        assert_eq!(escape("m~n"), "m~0n");
+        assert_eq!(escape("~/"), "~0~1");
+    }
+}
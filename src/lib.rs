@@ -1,12 +1,20 @@
 mod builders;
-#[cfg(feature = "client")]
+#[cfg(any(feature = "client", feature = "client-async"))]
 mod client;
 mod entities;
+mod graph;
+mod json_pointer;
+mod member_name;
+mod validation;
 
 #[cfg(test)]
 mod fixtures;
+#[cfg(all(test, any(feature = "client", feature = "client-async")))]
+mod test_server;
 
 pub use builders::*;
-#[cfg(feature = "client")]
+#[cfg(any(feature = "client", feature = "client-async"))]
 pub use client::*;
 pub use entities::*;
+pub use graph::*;
+pub use validation::*;
@@ -0,0 +1,119 @@
+// https://jsonapi.org/format/#document-member-names
+
+pub(crate) fn is_valid(name: &str) -> bool {
+    match name.split_once(':') {
+        // Extension members (https://jsonapi.org/format/#extension-members)
+        Some((namespace, name)) => {
+            is_valid_plain(namespace) && is_valid_plain(name)
+        }
+        None => is_valid_plain(name),
+    }
+}
+
+// https://jsonapi.org/format/#document-resource-object-fields
+pub(crate) fn is_reserved_field(name: &str) -> bool {
+    matches!(name, "id" | "type" | "relationships" | "links")
+}
+
+// https://jsonapi.org/recommendations/#naming
+pub(crate) fn is_recommended(name: &str) -> bool {
+    let name = match name.split_once(':') {
+        None => name,
+        Some((_, name)) => name,
+    };
+
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.ends_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_valid_plain(name: &str) -> bool {
+    // @-Members (https://jsonapi.org/format/#document-member-names-at-members)
+    let name = name.strip_prefix('@').unwrap_or(name);
+
+    let last = name.chars().count().wrapping_sub(1);
+
+    !name.is_empty()
+        && name.chars().enumerate().all(|(index, c)| {
+            is_globally_allowed(c)
+                || (index != 0 && index != last && is_allowed_inside(c))
+        })
+}
+
+fn is_globally_allowed(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c >= '\u{80}'
+}
+
+fn is_allowed_inside(c: char) -> bool {
+    c == '-' || c == '_' || c == ' '
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        for name in [
+            "a",
+            "foo",
+            "fooBar",
+            "foo-bar",
+            "foo_bar",
+            "foo bar",
+            "foo123",
+            "123",
+            "привет",
+            "@foo",
+            "atomic:operations",
+        ] {
+            assert!(is_valid(name), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for name in [
+            "",
+            "-foo",
+            "foo-",
+            "_foo",
+            "foo_",
+            " foo",
+            "foo ",
+            "foo.bar",
+            "foo/bar",
+            "foo[bar]",
+            "foo+bar",
+            "@",
+            ":foo",
+            "foo:",
+            "foo:bar:car",
+        ] {
+            assert!(!is_valid(name), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn reserved_field() {
+        for name in ["id", "type", "relationships", "links"] {
+            assert!(is_reserved_field(name), "{:?}", name);
+        }
+
+        for name in ["lid", "meta", "attributes", "ids", "Type"] {
+            assert!(!is_reserved_field(name), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn recommended() {
+        for name in ["foo", "fooBar", "foo123bar", "atomic:operations"] {
+            assert!(is_recommended(name), "{:?}", name);
+        }
+
+        for name in ["Foo", "foo-bar", "foo_bar", "foo123", "привет", "@foo"]
+        {
+            assert!(!is_recommended(name), "{:?}", name);
+        }
+    }
+}
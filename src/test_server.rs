@@ -0,0 +1,191 @@
+// A minimal HTTP/1.1 server which answers with prepared replies in the given
+// order, one per connection, and records the requests it has received.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::Value;
+
+pub const MIME: &str = "application/vnd.api+json";
+
+pub struct TestServer {
+    url: String,
+    requests: Receiver<Request>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct Reply {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    delay: Duration,
+}
+
+impl TestServer {
+    pub fn start(replies: Vec<Reply>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, requests) = mpsc::channel();
+
+        thread::spawn(move || {
+            for reply in replies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_string();
+                let path = parts.next().unwrap_or_default().to_string();
+
+                let mut headers = vec![];
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        headers.push((
+                            name.trim().to_lowercase(),
+                            value.trim().to_string(),
+                        ));
+                    }
+                }
+
+                let length = headers
+                    .iter()
+                    .find(|(name, _)| name == "content-length")
+                    .map_or(0, |(_, value)| value.parse().unwrap());
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+
+                let _ = sender.send(Request {
+                    method,
+                    path,
+                    headers,
+                    body: String::from_utf8(body).unwrap(),
+                });
+
+                let mut response =
+                    format!("HTTP/1.1 {} Reply\r\n", reply.status);
+                for (name, value) in &reply.headers {
+                    response.push_str(&format!("{}: {}\r\n", name, value));
+                }
+                response.push_str(&format!(
+                    "Content-Length: {}\r\nConnection: close\r\n\r\n",
+                    reply.body.len(),
+                ));
+                let mut response = response.into_bytes();
+                response.extend_from_slice(&reply.body);
+
+                thread::sleep(reply.delay);
+
+                // The client may have given up waiting.
+                let _ = stream.write_all(&response);
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn request(&self) -> Request {
+        self.requests.recv_timeout(Duration::from_secs(5)).unwrap()
+    }
+}
+
+// A server which never accepts connections. Its backlog is filled up, so
+// further connections can't be established and time out.
+pub struct UnresponsiveServer {
+    url: String,
+    _listener: TcpListener,
+    _connections: Vec<TcpStream>,
+}
+
+impl UnresponsiveServer {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let mut connections = vec![];
+
+        while let Ok(connection) =
+            TcpStream::connect_timeout(&address, Duration::from_millis(100))
+        {
+            connections.push(connection);
+        }
+
+        Self {
+            url: format!("http://{}", address),
+            _listener: listener,
+            _connections: connections,
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(other, _)| other == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn json(&self) -> Value {
+        serde_json::from_str(&self.body).unwrap()
+    }
+}
+
+impl Reply {
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: vec![],
+            delay: Duration::ZERO,
+        }
+    }
+
+    pub fn document(status: u16, document: Value) -> Self {
+        Self::new(status)
+            .header("Content-Type", MIME)
+            .body(document.to_string())
+    }
+
+    pub fn header<N: ToString, V: ToString>(self, name: N, value: V) -> Self {
+        let mut headers = self.headers;
+        headers.push((name.to_string(), value.to_string()));
+        Self { headers, ..self }
+    }
+
+    pub fn body<B: ToString>(self, body: B) -> Self {
+        self.bytes(body.to_string().into_bytes())
+    }
+
+    pub fn bytes(self, body: Vec<u8>) -> Self {
+        Self { body, ..self }
+    }
+
+    pub fn delay(self, delay: Duration) -> Self {
+        Self { delay, ..self }
+    }
+}
@@ -0,0 +1,866 @@
+use super::*;
+
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+
+use serde_json::Value;
+
+use crate::{json_pointer, member_name};
+
+pub fn validate(document: &Document) -> ValidationReport {
+    let version = match &document.jsonapi {
+        Some(JsonApi {
+            version: Some(version),
+            ..
+        }) => version.clone(),
+        _ => Version::default(),
+    };
+
+    let mut validator = Validator {
+        version,
+        report: ValidationReport::default(),
+    };
+
+    validator.document(document);
+    validator.report
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    Must,
+    Should,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ValidationReport(Vec<Finding>);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Finding {
+    pointer: String,
+    severity: Severity,
+    message: String,
+}
+
+impl Display for Severity {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Must => "MUST",
+            Self::Should => "SHOULD",
+        })
+    }
+}
+
+impl ValidationReport {
+    pub fn iter(&self) -> std::slice::Iter<'_, Finding> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // The document may still violate SHOULD requirements.
+    pub fn is_valid(&self) -> bool {
+        self.0
+            .iter()
+            .all(|finding| finding.severity != Severity::Must)
+    }
+
+    pub fn with_severity(
+        &self,
+        severity: Severity,
+    ) -> impl Iterator<Item = &Finding> {
+        self.0
+            .iter()
+            .filter(move |finding| finding.severity == severity)
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        for (index, finding) in self.0.iter().enumerate() {
+            if index != 0 {
+                formatter.write_str("; ")?;
+            }
+
+            Display::fmt(finding, formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+impl IntoIterator for ValidationReport {
+    type Item = Finding;
+    type IntoIter = std::vec::IntoIter<Finding>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationReport {
+    type Item = &'a Finding;
+    type IntoIter = std::slice::Iter<'a, Finding>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl Finding {
+    pub fn pointer(&self) -> &str {
+        &self.pointer
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{}: {} (at {:?})",
+            self.severity, self.message, self.pointer,
+        )
+    }
+}
+
+struct Validator {
+    version: Version,
+    report: ValidationReport,
+}
+
+fn child<T: Display>(pointer: &str, token: T) -> String {
+    format!("{}/{}", pointer, json_pointer::escape(&token.to_string()))
+}
+
+// Members of a map sorted by name, so that the report does not depend on the
+// order of members in the document.
+fn sorted<'m, V: 'm, I>(members: I) -> Vec<(&'m String, &'m V)>
+where
+    I: IntoIterator<Item = (&'m String, &'m V)>,
+{
+    let mut members: Vec<_> = members.into_iter().collect();
+    members.sort_unstable_by_key(|(name, _)| *name);
+    members
+}
+
+// Primary and included resources together with their locations.
+fn resources(document: &Document) -> Vec<(String, &Resource)> {
+    let mut resources = vec![];
+
+    match &document.data {
+        None | Some(Data::Null) => {}
+        Some(Data::Single(resource)) => {
+            resources.push(("/data".to_string(), resource))
+        }
+        Some(Data::Multiple(data)) => {
+            for (index, resource) in data.iter().enumerate() {
+                resources.push((child("/data", index), resource));
+            }
+        }
+    }
+
+    for (index, resource) in document.included.iter().flatten().enumerate() {
+        resources.push((child("/included", index), resource));
+    }
+
+    resources
+}
+
+impl Validator {
+    fn must<M: ToString>(&mut self, pointer: &str, message: M) {
+        self.add(pointer, Severity::Must, message);
+    }
+
+    fn should<M: ToString>(&mut self, pointer: &str, message: M) {
+        self.add(pointer, Severity::Should, message);
+    }
+
+    fn add<M: ToString>(
+        &mut self,
+        pointer: &str,
+        severity: Severity,
+        message: M,
+    ) {
+        self.report.0.push(Finding {
+            pointer: pointer.to_string(),
+            severity,
+            message: message.to_string(),
+        });
+    }
+
+    fn is_v1_1(&self) -> bool {
+        self.version >= Version::new(1)
+    }
+
+    // https://jsonapi.org/format/#document-top-level
+    fn document(&mut self, document: &Document) {
+        if document.data.is_some() && document.errors.is_some() {
+            self.must(
+                "/errors",
+                "document must not contain both data and errors",
+            );
+        }
+
+        if document.data.is_none() && document.included.is_some() {
+            self.must(
+                "/included",
+                "document must not contain included without data",
+            );
+        }
+
+        if document.data.is_none()
+            && document.errors.is_none()
+            && document.meta.is_none()
+        {
+            self.must(
+                "",
+                "document must contain at least one of data, errors or meta",
+            );
+        }
+
+        if let Some(jsonapi) = &document.jsonapi {
+            if let Some(meta) = &jsonapi.meta {
+                self.members("/jsonapi/meta", meta, false);
+            }
+        }
+
+        if let Some(meta) = &document.meta {
+            self.members("/meta", meta, false);
+        }
+
+        if let Some(links) = &document.links {
+            self.links("/links", links);
+        }
+
+        for (index, error_object) in
+            document.errors.iter().flatten().enumerate()
+        {
+            self.error_object(&child("/errors", index), error_object);
+        }
+
+        let resources = resources(document);
+
+        for (pointer, resource) in &resources {
+            self.resource(pointer, resource);
+        }
+
+        self.uniqueness(&resources);
+
+        if document.included.is_some() {
+            self.linkage(&resources);
+        }
+    }
+
+    fn name(&mut self, pointer: &str, name: &str, what: &str) {
+        if !member_name::is_valid(name) {
+            self.must(pointer, format!("invalid {}", what));
+            return;
+        }
+
+        let is_at_member = name.starts_with('@');
+
+        if (is_at_member || name.contains(':')) && !self.is_v1_1() {
+            self.must(
+                pointer,
+                format!(
+                    "{} with @-member or extension syntax requires JSON:API \
+                        1.1",
+                    what,
+                ),
+            );
+        } else if !is_at_member && !member_name::is_recommended(name) {
+            self.should(
+                pointer,
+                format!("{} does not follow naming recommendations", what),
+            );
+        }
+    }
+
+    // Checks names of arbitrary members recursively. Objects inside of
+    // attribute values can't have "relationships" or "links" members
+    // (https://jsonapi.org/format/#document-resource-object-attributes).
+    fn members<'m, I>(&mut self, pointer: &str, members: I, in_attribute: bool)
+    where
+        I: IntoIterator<Item = (&'m String, &'m Value)>,
+    {
+        for (name, value) in sorted(members) {
+            let pointer = child(pointer, name);
+
+            if in_attribute
+                && matches!(name.as_str(), "relationships" | "links")
+            {
+                self.must(&pointer, "reserved member in attribute value");
+            }
+
+            self.name(&pointer, name, "member name");
+            self.value(&pointer, value, in_attribute);
+        }
+    }
+
+    fn value(&mut self, pointer: &str, value: &Value, in_attribute: bool) {
+        match value {
+            Value::Object(object) => {
+                self.members(pointer, object, in_attribute)
+            }
+            Value::Array(array) => {
+                for (index, value) in array.iter().enumerate() {
+                    self.value(&child(pointer, index), value, in_attribute);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn links(&mut self, pointer: &str, links: &Links) {
+        for (name, link) in sorted(&links.other) {
+            let pointer = child(pointer, name);
+
+            self.name(&pointer, name, "member name");
+            self.link(&pointer, link);
+        }
+
+        for (name, link) in [
+            ("self", &links.self_),
+            ("related", &links.related),
+            ("first", &links.first),
+            ("last", &links.last),
+            ("prev", &links.prev),
+            ("next", &links.next),
+            ("about", &links.about),
+        ] {
+            if let Some(link) = link {
+                self.link(&child(pointer, name), link);
+            }
+        }
+    }
+
+    fn link(&mut self, pointer: &str, link: &Link) {
+        if let Link::Object(LinkObject {
+            meta: Some(meta), ..
+        }) = link
+        {
+            self.members(&child(pointer, "meta"), meta, false);
+        }
+    }
+
+    // https://jsonapi.org/format/#error-objects
+    fn error_object(&mut self, pointer: &str, error_object: &ErrorObject) {
+        if let Some(links) = &error_object.links {
+            self.links(&child(pointer, "links"), links);
+        }
+
+        if let Some(ErrorSource {
+            pointer: Some(source_pointer),
+            ..
+        }) = &error_object.source
+        {
+            if !json_pointer::is_valid(source_pointer) {
+                self.must(
+                    &child(&child(pointer, "source"), "pointer"),
+                    "invalid JSON pointer",
+                );
+            }
+        }
+
+        if let Some(meta) = &error_object.meta {
+            self.members(&child(pointer, "meta"), meta, false);
+        }
+    }
+
+    fn lid(&mut self, pointer: &str, lid: &Option<String>) {
+        if lid.is_some() && !self.is_v1_1() {
+            self.must(&child(pointer, "lid"), "lid requires JSON:API 1.1");
+        }
+    }
+
+    // https://jsonapi.org/format/#document-resource-objects
+    fn resource(&mut self, pointer: &str, resource: &Resource) {
+        self.name(&child(pointer, "type"), &resource.type_, "type");
+        self.lid(pointer, &resource.lid);
+
+        if let Some(meta) = &resource.meta {
+            self.members(&child(pointer, "meta"), meta, false);
+        }
+
+        if let Some(links) = &resource.links {
+            self.links(&child(pointer, "links"), links);
+        }
+
+        if let Some(attributes) = &resource.attributes {
+            let pointer = child(pointer, "attributes");
+
+            for (name, _) in sorted(attributes) {
+                if member_name::is_reserved_field(name) {
+                    self.must(&child(&pointer, name), "reserved field name");
+                }
+            }
+
+            self.members(&pointer, attributes, true);
+        }
+
+        if let Some(relationships) = &resource.relationships {
+            let pointer = child(pointer, "relationships");
+
+            for (name, relationship) in sorted(relationships) {
+                let pointer = child(&pointer, name);
+
+                if member_name::is_reserved_field(name) {
+                    self.must(&pointer, "reserved field name");
+                }
+
+                if let Some(attributes) = &resource.attributes {
+                    if attributes.contains_key(name) {
+                        self.must(&pointer, "field is already an attribute");
+                    }
+                }
+
+                self.name(&pointer, name, "member name");
+                self.relationship(&pointer, relationship);
+            }
+        }
+    }
+
+    // https://jsonapi.org/format/#document-resource-object-relationships
+    fn relationship(&mut self, pointer: &str, relationship: &Relationship) {
+        if relationship.meta.is_none()
+            && relationship.links.is_none()
+            && relationship.data.is_none()
+        {
+            self.must(
+                pointer,
+                "relationship must contain at least one of links, data or \
+                    meta",
+            );
+        }
+
+        if let Some(meta) = &relationship.meta {
+            self.members(&child(pointer, "meta"), meta, false);
+        }
+
+        if let Some(links) = &relationship.links {
+            self.links(&child(pointer, "links"), links);
+        }
+
+        match &relationship.data {
+            None | Some(IdentifierData::Null) => {}
+            Some(IdentifierData::Single(identifier)) => {
+                self.identifier(&child(pointer, "data"), identifier);
+            }
+            Some(IdentifierData::Multiple(identifiers)) => {
+                let pointer = child(pointer, "data");
+
+                for (index, identifier) in identifiers.iter().enumerate() {
+                    self.identifier(&child(&pointer, index), identifier);
+                }
+            }
+        }
+    }
+
+    // https://jsonapi.org/format/#document-resource-identifier-objects
+    fn identifier(&mut self, pointer: &str, identifier: &ResourceIdentifier) {
+        self.name(&child(pointer, "type"), &identifier.type_, "type");
+        self.lid(pointer, &identifier.lid);
+
+        if identifier.id.is_none() && identifier.lid.is_none() {
+            self.must(pointer, "resource identifier must contain id or lid");
+        }
+
+        if let Some(meta) = &identifier.meta {
+            self.members(&child(pointer, "meta"), meta, false);
+        }
+    }
+
+    // https://jsonapi.org/format/#document-resource-object-identification
+    fn uniqueness(&mut self, resources: &[(String, &Resource)]) {
+        let mut ids = HashSet::new();
+        let mut lids = HashSet::new();
+
+        for (pointer, resource) in resources {
+            let type_ = resource.type_.as_str();
+
+            let is_duplicate = match (&resource.id, &resource.lid) {
+                (Some(id), _) => !ids.insert((type_, id.as_str())),
+                (None, Some(lid)) => !lids.insert((type_, lid.as_str())),
+                (None, None) => false,
+            };
+
+            if is_duplicate {
+                self.must(pointer, "duplicate resource");
+            }
+        }
+    }
+
+    // Every included resource must be identified by primary data or by
+    // resource linkage in the same document
+    // (https://jsonapi.org/format/#document-compound-documents).
+    fn linkage(&mut self, resources: &[(String, &Resource)]) {
+        let mut identified = vec![];
+
+        for (pointer, resource) in resources {
+            if pointer.starts_with("/data") {
+                identified.push((&resource.type_, &resource.id, &resource.lid));
+            }
+
+            for relationship in
+                resource.relationships.iter().flat_map(|r| r.values())
+            {
+                let identifiers = match &relationship.data {
+                    None | Some(IdentifierData::Null) => &[][..],
+                    Some(IdentifierData::Single(identifier)) => {
+                        std::slice::from_ref(identifier)
+                    }
+                    Some(IdentifierData::Multiple(identifiers)) => {
+                        &identifiers[..]
+                    }
+                };
+
+                for identifier in identifiers {
+                    identified.push((
+                        &identifier.type_,
+                        &identifier.id,
+                        &identifier.lid,
+                    ));
+                }
+            }
+        }
+
+        for (pointer, resource) in resources {
+            if !pointer.starts_with("/included") {
+                continue;
+            }
+
+            let is_linked = identified.iter().any(|(type_, id, lid)| {
+                **type_ == resource.type_
+                    && ((id.is_some() && **id == resource.id)
+                        || (lid.is_some() && **lid == resource.lid))
+            });
+
+            if !is_linked {
+                self.must(pointer, "included resource is not linked");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn findings(document: Value) -> Vec<(Severity, String, String)> {
+        let document: Document = serde_json::from_value(document).unwrap();
+
+        validate(&document)
+            .into_iter()
+            .map(|finding| (finding.severity, finding.pointer, finding.message))
+            .collect()
+    }
+
+    fn must(pointer: &str, message: &str) -> (Severity, String, String) {
+        (Severity::Must, pointer.into(), message.into())
+    }
+
+    fn should(pointer: &str, message: &str) -> (Severity, String, String) {
+        (Severity::Should, pointer.into(), message.into())
+    }
+
+    #[test]
+    fn valid() {
+        let document: Document = serde_json::from_value(json!({
+            "meta": { "totalPages": 1 },
+            "links": { "self": "http://example.com/articles" },
+            "data": [{
+                "type": "articles",
+                "id": "1",
+                "attributes": {
+                    "title": "Hello",
+                    "tags": [{ "name": "news" }],
+                },
+                "relationships": {
+                    "author": {
+                        "data": { "type": "people", "id": "9" },
+                    },
+                    "comments": {
+                        "links": { "related": "http://example.com/c" },
+                        "data": [{ "type": "comments", "id": "5" }],
+                    },
+                },
+            }],
+            "included": [
+                { "type": "people", "id": "9" },
+                {
+                    "type": "comments",
+                    "id": "5",
+                    "relationships": {
+                        "author": { "data": null },
+                    },
+                },
+            ],
+        }))
+        .unwrap();
+
+        let report = validate(&document);
+
+        assert!(report.is_empty());
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn top_level() {
+        assert_eq!(
+            findings(json!({})),
+            vec![must(
+                "",
+                "document must contain at least one of data, errors or meta",
+            )],
+        );
+        assert_eq!(
+            findings(json!({ "data": null, "errors": [] })),
+            vec![must(
+                "/errors",
+                "document must not contain both data and errors"
+            )],
+        );
+        assert_eq!(
+            findings(json!({ "meta": {}, "included": [] })),
+            vec![must(
+                "/included",
+                "document must not contain included without data",
+            )],
+        );
+    }
+
+    #[test]
+    fn member_names() {
+        assert_eq!(
+            findings(json!({
+                "meta": {
+                    "foo-": 1,
+                    "foo_bar": 2,
+                    "nested": { "a/b": [{ "-c": 3 }] },
+                    "@context": 4,
+                },
+            })),
+            vec![
+                must(
+                    "/meta/@context",
+                    "member name with @-member or extension syntax requires \
+                        JSON:API 1.1",
+                ),
+                must("/meta/foo-", "invalid member name"),
+                should(
+                    "/meta/foo_bar",
+                    "member name does not follow naming recommendations",
+                ),
+                must("/meta/nested/a~1b", "invalid member name"),
+                must("/meta/nested/a~1b/0/-c", "invalid member name"),
+            ],
+        );
+    }
+
+    #[test]
+    fn member_names_v1_1() {
+        assert_eq!(
+            findings(json!({
+                "jsonapi": { "version": "1.1" },
+                "meta": { "@context": 1, "ext:fooBar": 2 },
+            })),
+            vec![],
+        );
+    }
+
+    #[test]
+    fn resource_fields() {
+        assert_eq!(
+            findings(json!({
+                "data": {
+                    "type": "Articles",
+                    "id": "1",
+                    "attributes": {
+                        "id": 1,
+                        "title": "Hello",
+                        "body": { "links": {} },
+                    },
+                    "relationships": {
+                        "title": { "meta": {} },
+                        "type": { "meta": {} },
+                        "author": {},
+                        "editor": { "data": { "type": "people" } },
+                    },
+                },
+            })),
+            vec![
+                should(
+                    "/data/type",
+                    "type does not follow naming recommendations"
+                ),
+                must("/data/attributes/id", "reserved field name"),
+                must(
+                    "/data/attributes/body/links",
+                    "reserved member in attribute value",
+                ),
+                must(
+                    "/data/relationships/author",
+                    "relationship must contain at least one of links, data or \
+                        meta",
+                ),
+                must(
+                    "/data/relationships/editor/data",
+                    "resource identifier must contain id or lid",
+                ),
+                must(
+                    "/data/relationships/title",
+                    "field is already an attribute",
+                ),
+                must("/data/relationships/type", "reserved field name"),
+            ],
+        );
+    }
+
+    #[test]
+    fn duplicate_resources() {
+        assert_eq!(
+            findings(json!({
+                "data": [
+                    { "type": "articles", "id": "1" },
+                    { "type": "articles", "id": "2" },
+                    { "type": "articles", "id": "1" },
+                ],
+            })),
+            vec![must("/data/2", "duplicate resource")],
+        );
+        assert_eq!(
+            findings(json!({
+                "data": {
+                    "type": "articles",
+                    "id": "1",
+                    "relationships": {
+                        "related": {
+                            "data": { "type": "articles", "id": "1" },
+                        },
+                    },
+                },
+                "included": [{ "type": "articles", "id": "1" }],
+            })),
+            vec![must("/included/0", "duplicate resource")],
+        );
+    }
+
+    #[test]
+    fn full_linkage() {
+        assert_eq!(
+            findings(json!({
+                "data": {
+                    "type": "articles",
+                    "id": "1",
+                    "relationships": {
+                        "author": {
+                            "data": { "type": "people", "id": "9" },
+                        },
+                    },
+                },
+                "included": [
+                    {
+                        "type": "people",
+                        "id": "9",
+                        "relationships": {
+                            "avatar": {
+                                "data": { "type": "images", "id": "3" },
+                            },
+                        },
+                    },
+                    { "type": "images", "id": "3" },
+                    { "type": "comments", "id": "5" },
+                ],
+            })),
+            vec![must("/included/2", "included resource is not linked")],
+        );
+    }
+
+    #[test]
+    fn error_source_pointer() {
+        assert_eq!(
+            findings(json!({
+                "errors": [
+                    { "source": { "pointer": "/data/attributes/title" } },
+                    { "source": { "pointer": "data/attributes/title" } },
+                ],
+            })),
+            vec![must("/errors/1/source/pointer", "invalid JSON pointer")],
+        );
+    }
+
+    #[test]
+    fn lid_version() {
+        let document = json!({
+            "data": {
+                "type": "articles",
+                "lid": "a",
+                "relationships": {
+                    "author": {
+                        "data": { "type": "people", "lid": "b" },
+                    },
+                },
+            },
+            "included": [{ "type": "people", "lid": "b" }],
+        });
+
+        assert_eq!(
+            findings(document.clone()),
+            vec![
+                must("/data/lid", "lid requires JSON:API 1.1"),
+                must(
+                    "/data/relationships/author/data/lid",
+                    "lid requires JSON:API 1.1",
+                ),
+                must("/included/0/lid", "lid requires JSON:API 1.1"),
+            ],
+        );
+
+        let mut document = document;
+        document["jsonapi"] = json!({ "version": "1.1" });
+
+        assert_eq!(findings(document), vec![]);
+    }
+
+    #[test]
+    fn report() {
+        let document: Document = serde_json::from_value(json!({
+            "meta": { "foo_bar": 1, "-": 2 },
+        }))
+        .unwrap();
+
+        let report = validate(&document);
+
+        assert_eq!(report.len(), 2);
+        assert!(!report.is_valid());
+        assert_eq!(
+            report
+                .with_severity(Severity::Should)
+                .map(Finding::pointer)
+                .collect::<Vec<_>>(),
+            vec!["/meta/foo_bar"],
+        );
+        assert_eq!(
+            report.to_string(),
+            "MUST: invalid member name (at \"/meta/-\"); SHOULD: member name \
+                does not follow naming recommendations (at \"/meta/foo_bar\")",
+        );
+    }
+}